@@ -1,3 +1,5 @@
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+
 pub(crate) struct Concat<I: Iterator> {
     xss: I,
     xs: Option<I::Item>,
@@ -33,3 +35,115 @@ where
         }
     }
 }
+
+pub struct Interleave<I: Iterator, J: Iterator<Item = I::Item>> {
+    pub(crate) xs: I,
+    pub(crate) ys: J,
+    x_next: bool,
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> Interleave<I, J> {
+    pub(crate) fn new(xs: I, ys: J) -> Interleave<I, J> {
+        Interleave {
+            xs,
+            ys,
+            x_next: true,
+        }
+    }
+
+    fn step_nth(&mut self, n: usize) -> Option<I::Item> {
+        for _ in 0..n {
+            self.next()?;
+        }
+        self.next()
+    }
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> Iterator for Interleave<I, J> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        // Once one of the iterators runs out, the remaining elements of the other are generated in
+        // order.
+        let x_next = self.x_next;
+        self.x_next = !x_next;
+        if x_next {
+            self.xs.next().or_else(|| self.ys.next())
+        } else {
+            self.ys.next().or_else(|| self.xs.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (xs_lower, xs_upper) = self.xs.size_hint();
+        let (ys_lower, ys_upper) = self.ys.size_hint();
+        let upper = match (xs_upper, ys_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (xs_lower.saturating_add(ys_lower), upper)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item> {
+        // Work out how many values are taken from each iterator, which is possible if their
+        // lengths are known or they are long enough.
+        let (firsts, seconds) = if self.x_next {
+            (self.xs.size_hint(), self.ys.size_hint())
+        } else {
+            (self.ys.size_hint(), self.xs.size_hint())
+        };
+        let mut first_count = n - n / 2;
+        let mut second_count = n / 2;
+        if first_count > firsts.0 {
+            if firsts.1 != Some(firsts.0) {
+                return self.step_nth(n);
+            }
+            first_count = firsts.0;
+            second_count = n - first_count;
+        } else if second_count > seconds.0 {
+            if seconds.1 != Some(seconds.0) {
+                return self.step_nth(n);
+            }
+            second_count = seconds.0;
+            first_count = n - second_count;
+        }
+        let (xs_count, ys_count) = if self.x_next {
+            (first_count, second_count)
+        } else {
+            (second_count, first_count)
+        };
+        if xs_count != 0 {
+            self.xs.nth(xs_count - 1);
+        }
+        if ys_count != 0 {
+            self.ys.nth(ys_count - 1);
+        }
+        self.x_next ^= n % 2 == 1;
+        self.next()
+    }
+}
+
+// The index of the ith value of one of two interleaved iterators, given the length of the other
+// one, or None if it doesn't fit in a u64. The first value comes from xs.
+pub(crate) fn interleaved_index(i: u64, in_xs: bool, other_len: Option<u64>) -> Option<u64> {
+    match other_len {
+        Some(len) if i >= len => i.checked_add(len),
+        _ => i.checked_mul(2)?.checked_add(u64::from(!in_xs)),
+    }
+}
+
+impl<I: Iterator + Resumable, J: Iterator<Item = I::Item> + Resumable> Resumable
+    for Interleave<I, J>
+{
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.xs.save_state(state);
+        self.ys.save_state(state);
+        self.x_next.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.xs.restore_state(state)?;
+        self.ys.restore_state(state)?;
+        self.x_next.restore_state(state)
+    }
+}
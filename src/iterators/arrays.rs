@@ -0,0 +1,101 @@
+use std::array::from_fn;
+use std::convert::TryFrom;
+
+use malachite_base::num::conversion::traits::ExactFrom;
+
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
+use iterators::generators::Generator;
+use iterators::vecs::{exhaustive_vecs_fixed_length, ExhaustiveVecsFixedLength};
+
+pub struct RandomArrays<I: Iterator, const N: usize> {
+    xs: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for RandomArrays<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        let xs = &mut self.xs;
+        Some(from_fn(|_| xs.next().unwrap()))
+    }
+}
+
+impl<I: Iterator + Resumable, const N: usize> Resumable for RandomArrays<I, N> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.xs.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.xs.restore_state(state)
+    }
+}
+
+pub fn random_arrays<G: Generator + ?Sized, const N: usize>(
+    seed: &Seed,
+    xs_gen: &G,
+) -> RandomArrays<G::Iter, N> {
+    RandomArrays {
+        xs: xs_gen.generate(&seed.derive("xs")),
+    }
+}
+
+pub struct ExhaustiveArrays<I: Iterator, const N: usize>(ExhaustiveVecsFixedLength<I>)
+where
+    I::Item: Clone;
+
+impl<I: Iterator, const N: usize> Iterator for ExhaustiveArrays<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        self.0.next().map(|xs| match <[I::Item; N]>::try_from(xs) {
+            Ok(xs) => xs,
+            Err(_) => unreachable!(),
+        })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<[I::Item; N]> {
+        self.0.nth(n).map(|xs| match <[I::Item; N]>::try_from(xs) {
+            Ok(xs) => xs,
+            Err(_) => unreachable!(),
+        })
+    }
+}
+
+impl<I: Iterator, const N: usize> ExhaustiveArrays<I, N>
+where
+    I::Item: Clone,
+{
+    // Returns the index of xs, or None if one of its values hasn't been generated by the input
+    // yet, or if its index doesn't fit in a u64. The input values are assumed to be distinct.
+    pub fn index_of(&mut self, xs: &[I::Item; N]) -> Option<u64>
+    where
+        I::Item: PartialEq,
+    {
+        self.0.index_of(xs)
+    }
+}
+
+impl<I: Iterator, const N: usize> Resumable for ExhaustiveArrays<I, N>
+where
+    I::Item: Clone,
+{
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.0.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.0.restore_state(state)
+    }
+}
+
+// xs may be finite or infinite.
+pub fn exhaustive_arrays<I: Iterator, const N: usize>(xs: I) -> ExhaustiveArrays<I, N>
+where
+    I::Item: Clone,
+{
+    ExhaustiveArrays(exhaustive_vecs_fixed_length(xs, u64::exact_from(N)))
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use malachite_base::num::conversion::traits::CheckedFrom;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CheckpointError {
+    // The checkpoint ended before the generator's state was complete.
+    Truncated,
+    // The checkpoint had values left over after the generator's state was complete.
+    TrailingValues,
+    // A value doesn't fit the generator, e.g. the checkpoint was saved by a different generator.
+    InvalidValue(u64),
+    Malformed,
+    // The generator can't be restored at all, e.g. because its RNG draws from bytes shared with
+    // other generators.
+    Unsupported,
+}
+
+impl Display for CheckpointError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            CheckpointError::Truncated => write!(f, "the checkpoint is too short"),
+            CheckpointError::TrailingValues => write!(f, "the checkpoint is too long"),
+            CheckpointError::InvalidValue(x) => {
+                write!(f, "the checkpoint contains an invalid value: {}", x)
+            }
+            CheckpointError::Malformed => {
+                write!(
+                    f,
+                    "a checkpoint must be written as comma-separated integers"
+                )
+            }
+            CheckpointError::Unsupported => write!(f, "the generator can't be checkpointed"),
+        }
+    }
+}
+
+impl Error for CheckpointError {}
+
+// The position of a generator, as a flat list of counters. It only makes sense together with the
+// arguments the generator was created with: to replay from a checkpoint, create the generator
+// again with the same seed and arguments and call `resume`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Checkpoint(pub Vec<u64>);
+
+impl Display for Checkpoint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, x) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", x)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Checkpoint {
+    type Err = CheckpointError;
+
+    fn from_str(s: &str) -> Result<Checkpoint, CheckpointError> {
+        if s.is_empty() {
+            return Ok(Checkpoint::default());
+        }
+        s.split(',')
+            .map(|x| x.trim().parse().map_err(|_| CheckpointError::Malformed))
+            .collect::<Result<Vec<u64>, _>>()
+            .map(Checkpoint)
+    }
+}
+
+pub struct StateReader<'a> {
+    state: &'a [u64],
+    i: usize,
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(state: &'a [u64]) -> StateReader<'a> {
+        StateReader { state, i: 0 }
+    }
+
+    pub fn read(&mut self) -> Result<u64, CheckpointError> {
+        let x = *self.state.get(self.i).ok_or(CheckpointError::Truncated)?;
+        self.i += 1;
+        Ok(x)
+    }
+
+    // Reads an index or length, which must be less than limit.
+    pub fn read_usize(&mut self, limit: usize) -> Result<usize, CheckpointError> {
+        let x = self.read()?;
+        match usize::checked_from(x) {
+            Some(i) if i < limit => Ok(i),
+            _ => Err(CheckpointError::InvalidValue(x)),
+        }
+    }
+
+    pub fn finish(self) -> Result<(), CheckpointError> {
+        if self.i == self.state.len() {
+            Ok(())
+        } else {
+            Err(CheckpointError::TrailingValues)
+        }
+    }
+}
+
+// An iterator whose position can be saved and restored. Restoring doesn't depend on how far the
+// iterator has already advanced, as long as it was created with the same arguments as the one
+// that was saved.
+pub trait Resumable {
+    fn save_state(&self, state: &mut Vec<u64>);
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError>;
+
+    fn checkpoint(&self) -> Checkpoint {
+        let mut state = Vec::new();
+        self.save_state(&mut state);
+        Checkpoint(state)
+    }
+
+    fn resume(&mut self, checkpoint: &Checkpoint) -> Result<(), CheckpointError> {
+        let mut state = StateReader::new(&checkpoint.0);
+        self.restore_state(&mut state)?;
+        state.finish()
+    }
+}
+
+impl Resumable for bool {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(u64::from(*self));
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        *self = match state.read()? {
+            0 => false,
+            1 => true,
+            x => return Err(CheckpointError::InvalidValue(x)),
+        };
+        Ok(())
+    }
+}
+
+// Implements `Resumable` for a struct by saving and restoring the listed fields, in order.
+macro_rules! resumable {
+    ([$($generics: tt)*], $struct_type: ty, [$($field: tt),*], [$($bounds: tt)*]) => {
+        impl<$($generics)*> ::iterators::checkpoints::Resumable for $struct_type
+        where
+            $($bounds)*
+        {
+            fn save_state(&self, state: &mut Vec<u64>) {
+                $(
+                    ::iterators::checkpoints::Resumable::save_state(&self.$field, state);
+                )*
+            }
+
+            fn restore_state(
+                &mut self,
+                state: &mut ::iterators::checkpoints::StateReader,
+            ) -> Result<(), ::iterators::checkpoints::CheckpointError> {
+                $(
+                    ::iterators::checkpoints::Resumable::restore_state(&mut self.$field, state)?;
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    // Checks that after the first n values, an iterator continues with the same values once
+    // resumed from a checkpoint, whether the iterator it is resumed into is new or has already
+    // gone further.
+    pub(crate) fn assert_resumes<I: Iterator + Resumable, F: Fn() -> I>(new: F, n: usize)
+    where
+        I::Item: Debug + PartialEq,
+    {
+        let mut xs = new();
+        xs.by_ref().take(n).count();
+        let checkpoint = xs.checkpoint();
+        let expected: Vec<I::Item> = xs.take(n).collect();
+        let mut fresh = new();
+        fresh.resume(&checkpoint).unwrap();
+        assert_eq!(fresh.take(n).collect::<Vec<_>>(), expected);
+        let mut ahead = new();
+        ahead.by_ref().take(3 * n).count();
+        ahead.resume(&checkpoint).unwrap();
+        assert_eq!(ahead.take(n).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_checkpoint_from_str() {
+        let checkpoint = Checkpoint(vec![0, 1, u64::MAX]);
+        assert_eq!(checkpoint.to_string(), "0,1,18446744073709551615");
+        assert_eq!(checkpoint.to_string().parse(), Ok(checkpoint));
+        assert_eq!("".parse(), Ok(Checkpoint::default()));
+        assert_eq!("1,x".parse::<Checkpoint>(), Err(CheckpointError::Malformed));
+    }
+}
@@ -1,9 +1,11 @@
 use iterators::adaptors::Concat;
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
 use iterators::general::CachedIterator;
 use iterators::tuples::{LogPairIndices, ZOrderTupleIndices};
 use malachite_base::num::conversion::traits::ExactFrom;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
+use std::rc::Rc;
 
 pub fn dependent_pairs<'a, I: Iterator + 'a, J: Iterator, F: 'a>(
     xs: I,
@@ -18,6 +20,40 @@ where
     ))
 }
 
+// The exhaustive dependent pairs save the ys of each x by the index of x in xs: 0 if they haven't
+// been created, 1 followed by their state if they are active, and 2 if they are exhausted. On
+// restoring, active ys are created again by f. The random dependent pairs are not resumable, since
+// their xs can't be recovered from a checkpoint.
+fn save_dependent_ys<J: Resumable>(state: &mut Vec<u64>, ys: Option<Option<&J>>) {
+    match ys {
+        None => state.push(0),
+        Some(Some(ys)) => {
+            state.push(1);
+            ys.save_state(state);
+        }
+        Some(None) => state.push(2),
+    }
+}
+
+fn restore_dependent_ys<J: Resumable, G>(
+    state: &mut StateReader,
+    new_ys: G,
+) -> Result<Option<Option<J>>, CheckpointError>
+where
+    G: FnOnce() -> J,
+{
+    match state.read()? {
+        0 => Ok(None),
+        1 => {
+            let mut ys = new_ys();
+            ys.restore_state(state)?;
+            Ok(Some(Some(ys)))
+        }
+        2 => Ok(Some(None)),
+        x => Err(CheckpointError::InvalidValue(x)),
+    }
+}
+
 pub struct RandomDependentPairs<I: Iterator, J: Iterator, F, T>
 where
     F: Fn(&T, &I::Item) -> J,
@@ -62,6 +98,76 @@ where
     }
 }
 
+pub struct RandomDependentPairsBounded<I: Iterator, J: Iterator, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+{
+    xs: I,
+    f: F,
+    data: T,
+    max_ys: usize,
+    // Each x is mapped to its ys and the time the ys were last used.
+    x_to_ys: HashMap<I::Item, (J, u64)>,
+    last_used: BTreeMap<u64, I::Item>,
+    time: u64,
+}
+
+impl<I: Iterator, J: Iterator, F, T> Iterator for RandomDependentPairsBounded<I, J, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+    I::Item: Clone + Eq + Hash,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)> {
+        let x = self.xs.next().unwrap();
+        let time = self.time;
+        self.time += 1;
+        let y = if let Some(entry) = self.x_to_ys.get_mut(&x) {
+            self.last_used.remove(&entry.1);
+            self.last_used.insert(time, x.clone());
+            entry.1 = time;
+            entry.0.next().unwrap()
+        } else {
+            if self.x_to_ys.len() == self.max_ys {
+                // Drop the least recently used ys. If its x appears again, its ys start over.
+                let oldest = *self.last_used.keys().next().unwrap();
+                let old_x = self.last_used.remove(&oldest).unwrap();
+                self.x_to_ys.remove(&old_x);
+            }
+            let mut ys = (self.f)(&self.data, &x);
+            let y = ys.next().unwrap();
+            self.x_to_ys.insert(x.clone(), (ys, time));
+            self.last_used.insert(time, x.clone());
+            y
+        };
+        Some((x, y))
+    }
+}
+
+// Like random_dependent_pairs, but at most max_ys iterators of ys are kept alive at once.
+pub fn random_dependent_pairs_bounded<I: Iterator, J: Iterator, F, T>(
+    max_ys: usize,
+    data: T,
+    xs: I,
+    f: F,
+) -> RandomDependentPairsBounded<I, J, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+    I::Item: Clone + Eq + Hash,
+{
+    assert_ne!(max_ys, 0, "max_ys cannot be 0");
+    RandomDependentPairsBounded {
+        xs,
+        f,
+        data,
+        max_ys,
+        x_to_ys: HashMap::new(),
+        last_used: BTreeMap::new(),
+        time: 0,
+    }
+}
+
 macro_rules! exhaustive_dependent_pairs {
     (
         $struct_name:ident,
@@ -102,6 +208,46 @@ macro_rules! exhaustive_dependent_pairs {
             }
         }
 
+        // The ys of an x that appears more than once in xs are saved at its first index.
+        impl<I: Iterator, J: Iterator + Resumable, F, T> Resumable for $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone + Eq + Hash,
+        {
+            fn save_state(&self, state: &mut Vec<u64>) {
+                self.xs.save_state(state);
+                self.i.save_state(state);
+                let mut seen = HashSet::new();
+                for x in self.xs.generated() {
+                    if seen.insert(x) {
+                        save_dependent_ys(state, self.x_to_ys.get(x).map(Some));
+                    }
+                }
+            }
+
+            fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+                self.xs.restore_state(state)?;
+                self.i.restore_state(state)?;
+                self.x_to_ys.clear();
+                let f = &self.f;
+                let data = &self.data;
+                let mut seen = HashSet::new();
+                for x in self.xs.generated() {
+                    if !seen.insert(x) {
+                        continue;
+                    }
+                    match restore_dependent_ys(state, || f(data, x))? {
+                        Some(Some(ys)) => {
+                            self.x_to_ys.insert(x.clone(), ys);
+                        }
+                        Some(None) => return Err(CheckpointError::InvalidValue(2)),
+                        None => {}
+                    }
+                }
+                Ok(())
+            }
+        }
+
         pub fn $fn_name<I: Iterator, J: Iterator, F, T>(
             data: T,
             xs: I,
@@ -136,3 +282,590 @@ exhaustive_dependent_pairs!(
     ZOrderTupleIndices::new(2),
     |i: &ZOrderTupleIndices| usize::exact_from(i.0[1])
 );
+
+macro_rules! exhaustive_dependent_pairs_finite {
+    (
+        $struct_name:ident,
+        $fn_name:ident,
+        $index_type:ident,
+        $index_ctor:expr,
+        $x_index_fn:expr
+    ) => {
+        pub struct $struct_name<I: Iterator, J: Iterator, F, T>
+        where
+            I::Item: Clone,
+        {
+            f: F,
+            xs: CachedIterator<I>,
+            // None once the ys corresponding to an x are exhausted.
+            x_to_ys: HashMap<I::Item, Option<J>>,
+            i: $index_type,
+            // Once xs is known to be finite, the xs are visited in round-robin order.
+            next_xi: usize,
+            skipped: usize,
+            data: T,
+        }
+
+        impl<I: Iterator, J: Iterator, F, T> $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone + Eq + Hash,
+        {
+            fn next_y(&mut self, x: &I::Item) -> Option<J::Item> {
+                let f = &self.f;
+                let data = &self.data;
+                let ys = self
+                    .x_to_ys
+                    .entry(x.clone())
+                    .or_insert_with(|| Some(f(data, x)));
+                if let Some(ref mut ys_inner) = *ys {
+                    if let Some(y) = ys_inner.next() {
+                        return Some(y);
+                    }
+                }
+                *ys = None;
+                None
+            }
+        }
+
+        impl<I: Iterator, J: Iterator, F, T> Iterator for $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone + Eq + Hash,
+        {
+            type Item = (I::Item, J::Item);
+
+            fn next(&mut self) -> Option<(I::Item, J::Item)> {
+                loop {
+                    let round_robin = self.xs.known_len().is_some();
+                    let xi = match self.xs.known_len() {
+                        Some(len) => {
+                            if self.skipped >= len {
+                                // Every x has been visited since a y was last generated, so all
+                                // the ys are exhausted.
+                                return None;
+                            }
+                            let xi = self.next_xi;
+                            self.next_xi = if xi + 1 == len { 0 } else { xi + 1 };
+                            xi
+                        }
+                        None => {
+                            let xi = $x_index_fn(&self.i);
+                            self.i.increment();
+                            xi
+                        }
+                    };
+                    if let Some(x) = self.xs.get(xi) {
+                        if let Some(y) = self.next_y(&x) {
+                            self.skipped = 0;
+                            return Some((x, y));
+                        }
+                    }
+                    if round_robin {
+                        self.skipped += 1;
+                    }
+                }
+            }
+        }
+
+        // The ys of an x that appears more than once in xs are saved at its first index.
+        impl<I: Iterator, J: Iterator + Resumable, F, T> Resumable for $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone + Eq + Hash,
+        {
+            fn save_state(&self, state: &mut Vec<u64>) {
+                self.xs.save_state(state);
+                self.i.save_state(state);
+                state.push(u64::exact_from(self.next_xi));
+                state.push(u64::exact_from(self.skipped));
+                let mut seen = HashSet::new();
+                for x in self.xs.generated() {
+                    if seen.insert(x) {
+                        save_dependent_ys(state, self.x_to_ys.get(x).map(Option::as_ref));
+                    }
+                }
+            }
+
+            fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+                self.xs.restore_state(state)?;
+                self.i.restore_state(state)?;
+                self.next_xi = state.read_usize(usize::MAX)?;
+                self.skipped = state.read_usize(usize::MAX)?;
+                self.x_to_ys.clear();
+                let f = &self.f;
+                let data = &self.data;
+                let mut seen = HashSet::new();
+                for x in self.xs.generated() {
+                    if !seen.insert(x) {
+                        continue;
+                    }
+                    if let Some(ys) = restore_dependent_ys(state, || f(data, x))? {
+                        self.x_to_ys.insert(x.clone(), ys);
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        // xs and the iterators generated by f may be finite or infinite.
+        pub fn $fn_name<I: Iterator, J: Iterator, F, T>(
+            data: T,
+            xs: I,
+            f: F,
+        ) -> $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone + Eq + Hash,
+        {
+            $struct_name {
+                f,
+                xs: CachedIterator::new(xs),
+                x_to_ys: HashMap::new(),
+                i: $index_ctor,
+                next_xi: 0,
+                skipped: 0,
+                data,
+            }
+        }
+    };
+}
+
+exhaustive_dependent_pairs_finite!(
+    ExhaustiveDependentPairsLog,
+    exhaustive_dependent_pairs_log,
+    LogPairIndices,
+    LogPairIndices::new(),
+    |i: &LogPairIndices| i.indices().1
+);
+exhaustive_dependent_pairs_finite!(
+    ExhaustiveDependentPairs,
+    exhaustive_dependent_pairs,
+    ZOrderTupleIndices,
+    ZOrderTupleIndices::new(2),
+    |i: &ZOrderTupleIndices| usize::exact_from(i.0[1])
+);
+
+// The ys corresponding to some x.
+enum DependentYs<J: Iterator> {
+    Unused,
+    Active(J),
+    Exhausted,
+}
+
+// The ys are stored by the index of their x in xs rather than by the x itself, so the xs need not
+// be hashable and large xs are never hashed.
+fn next_y_indexed<J: Iterator, G>(
+    x_to_ys: &mut Vec<DependentYs<J>>,
+    xi: usize,
+    new_ys: G,
+) -> Option<J::Item>
+where
+    G: FnOnce() -> J,
+{
+    while x_to_ys.len() <= xi {
+        x_to_ys.push(DependentYs::Unused);
+    }
+    if let DependentYs::Unused = x_to_ys[xi] {
+        x_to_ys[xi] = DependentYs::Active(new_ys());
+    }
+    if let DependentYs::Active(ref mut ys) = x_to_ys[xi] {
+        if let Some(y) = ys.next() {
+            return Some(y);
+        }
+    }
+    x_to_ys[xi] = DependentYs::Exhausted;
+    None
+}
+
+pub struct RandomDependentPairsIndexed<I: Iterator, J: Iterator, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+{
+    xs: I,
+    f: F,
+    data: T,
+}
+
+impl<I: Iterator, J: Iterator, F, T> Iterator for RandomDependentPairsIndexed<I, J, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)> {
+        let x = self.xs.next()?;
+        let y = (self.f)(&self.data, &x).next().unwrap();
+        Some((x, y))
+    }
+}
+
+// Like random_dependent_pairs, but the ys are keyed by the position of their x in xs rather than
+// by the x itself. Each x gets its own ys, which are created by f and used only for that x, so
+// equal xs don't share ys and no x is stored, compared or hashed. The pairs end when xs ends. The
+// iterators generated by f must be nonempty.
+pub fn random_dependent_pairs_indexed<I: Iterator, J: Iterator, F, T>(
+    data: T,
+    xs: I,
+    f: F,
+) -> RandomDependentPairsIndexed<I, J, F, T>
+where
+    F: Fn(&T, &I::Item) -> J,
+{
+    RandomDependentPairsIndexed { xs, f, data }
+}
+
+macro_rules! exhaustive_dependent_pairs_indexed {
+    (
+        $struct_name:ident,
+        $fn_name:ident,
+        $index_type:ident,
+        $index_ctor:expr,
+        $x_index_fn:expr
+    ) => {
+        pub struct $struct_name<I: Iterator, J: Iterator, F, T>
+        where
+            I::Item: Clone,
+        {
+            f: F,
+            xs: CachedIterator<I>,
+            x_to_ys: Vec<DependentYs<J>>,
+            i: $index_type,
+            // Once xs is known to be finite, the xs are visited in round-robin order.
+            next_xi: usize,
+            skipped: usize,
+            data: T,
+        }
+
+        impl<I: Iterator, J: Iterator, F, T> Iterator for $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone,
+        {
+            type Item = (I::Item, J::Item);
+
+            fn next(&mut self) -> Option<(I::Item, J::Item)> {
+                loop {
+                    let round_robin = self.xs.known_len().is_some();
+                    let xi = match self.xs.known_len() {
+                        Some(len) => {
+                            if self.skipped >= len {
+                                // Every x has been visited since a y was last generated, so all
+                                // the ys are exhausted.
+                                return None;
+                            }
+                            let xi = self.next_xi;
+                            self.next_xi = if xi + 1 == len { 0 } else { xi + 1 };
+                            xi
+                        }
+                        None => {
+                            let xi = $x_index_fn(&self.i);
+                            self.i.increment();
+                            xi
+                        }
+                    };
+                    if let Some(x) = self.xs.get(xi) {
+                        let f = &self.f;
+                        let data = &self.data;
+                        if let Some(y) = next_y_indexed(&mut self.x_to_ys, xi, || f(data, &x)) {
+                            self.skipped = 0;
+                            return Some((x, y));
+                        }
+                    }
+                    if round_robin {
+                        self.skipped += 1;
+                    }
+                }
+            }
+        }
+
+        impl<I: Iterator, J: Iterator + Resumable, F, T> Resumable for $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone,
+        {
+            fn save_state(&self, state: &mut Vec<u64>) {
+                self.xs.save_state(state);
+                self.i.save_state(state);
+                state.push(u64::exact_from(self.next_xi));
+                state.push(u64::exact_from(self.skipped));
+                state.push(u64::exact_from(self.x_to_ys.len()));
+                for ys in &self.x_to_ys {
+                    save_dependent_ys(
+                        state,
+                        match *ys {
+                            DependentYs::Unused => None,
+                            DependentYs::Active(ref ys) => Some(Some(ys)),
+                            DependentYs::Exhausted => Some(None),
+                        },
+                    );
+                }
+            }
+
+            fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+                self.xs.restore_state(state)?;
+                self.i.restore_state(state)?;
+                self.next_xi = state.read_usize(usize::MAX)?;
+                self.skipped = state.read_usize(usize::MAX)?;
+                let xs = self.xs.generated();
+                let len = state.read_usize(xs.len() + 1)?;
+                self.x_to_ys.clear();
+                let f = &self.f;
+                let data = &self.data;
+                for x in &xs[..len] {
+                    self.x_to_ys
+                        .push(match restore_dependent_ys(state, || f(data, x))? {
+                            None => DependentYs::Unused,
+                            Some(Some(ys)) => DependentYs::Active(ys),
+                            Some(None) => DependentYs::Exhausted,
+                        });
+                }
+                Ok(())
+            }
+        }
+
+        // xs and the iterators generated by f may be finite or infinite.
+        pub fn $fn_name<I: Iterator, J: Iterator, F, T>(
+            data: T,
+            xs: I,
+            f: F,
+        ) -> $struct_name<I, J, F, T>
+        where
+            F: Fn(&T, &I::Item) -> J,
+            I::Item: Clone,
+        {
+            $struct_name {
+                f,
+                xs: CachedIterator::new(xs),
+                x_to_ys: Vec::new(),
+                i: $index_ctor,
+                next_xi: 0,
+                skipped: 0,
+                data,
+            }
+        }
+    };
+}
+
+exhaustive_dependent_pairs_indexed!(
+    ExhaustiveDependentPairsIndexedLog,
+    exhaustive_dependent_pairs_indexed_log,
+    LogPairIndices,
+    LogPairIndices::new(),
+    |i: &LogPairIndices| i.indices().1
+);
+exhaustive_dependent_pairs_indexed!(
+    ExhaustiveDependentPairsIndexed,
+    exhaustive_dependent_pairs_indexed,
+    ZOrderTupleIndices,
+    ZOrderTupleIndices::new(2),
+    |i: &ZOrderTupleIndices| usize::exact_from(i.0[1])
+);
+
+// The context data is shared by every level of the generated tuples.
+pub fn random_dependent_triples<'a, I, J, K, F, G, T>(
+    data: T,
+    xs: I,
+    f: F,
+    g: G,
+) -> Box<dyn Iterator<Item = (I::Item, J::Item, K::Item)> + 'a>
+where
+    I: Iterator + 'a,
+    J: Iterator + 'a,
+    K: Iterator + 'a,
+    F: Fn(&T, &I::Item) -> J + 'a,
+    G: Fn(&T, &I::Item, &J::Item) -> K + 'a,
+    T: 'a,
+    I::Item: Clone + Eq + Hash,
+    J::Item: Clone + Eq + Hash,
+{
+    let data = Rc::new(data);
+    let xys = random_dependent_pairs(data.clone(), xs, move |data: &Rc<T>, x: &I::Item| {
+        f(&**data, x)
+    });
+    Box::new(
+        random_dependent_pairs(data, xys, move |data: &Rc<T>, xy: &(I::Item, J::Item)| {
+            g(&**data, &xy.0, &xy.1)
+        })
+        .map(|((x, y), z)| (x, y, z)),
+    )
+}
+
+pub fn random_dependent_quadruples<'a, I, J, K, L, F, G, H, T>(
+    data: T,
+    xs: I,
+    f: F,
+    g: G,
+    h: H,
+) -> Box<dyn Iterator<Item = (I::Item, J::Item, K::Item, L::Item)> + 'a>
+where
+    I: Iterator + 'a,
+    J: Iterator + 'a,
+    K: Iterator + 'a,
+    L: Iterator + 'a,
+    F: Fn(&T, &I::Item) -> J + 'a,
+    G: Fn(&T, &I::Item, &J::Item) -> K + 'a,
+    H: Fn(&T, &I::Item, &J::Item, &K::Item) -> L + 'a,
+    T: 'a,
+    I::Item: Clone + Eq + Hash,
+    J::Item: Clone + Eq + Hash,
+    K::Item: Clone + Eq + Hash,
+{
+    let data = Rc::new(data);
+    let xyzs = random_dependent_triples(
+        data.clone(),
+        xs,
+        move |data: &Rc<T>, x: &I::Item| f(&**data, x),
+        move |data: &Rc<T>, x: &I::Item, y: &J::Item| g(&**data, x, y),
+    );
+    Box::new(
+        random_dependent_pairs(
+            data,
+            xyzs,
+            move |data: &Rc<T>, xyz: &(I::Item, J::Item, K::Item)| {
+                h(&**data, &xyz.0, &xyz.1, &xyz.2)
+            },
+        )
+        .map(|((x, y, z), w)| (x, y, z, w)),
+    )
+}
+
+// Each level is generated fairly with respect to the previous levels, in the same way as
+// exhaustive_dependent_pairs_indexed. Every iterator may be finite or infinite.
+pub fn exhaustive_dependent_triples<'a, I, J, K, F, G, T>(
+    data: T,
+    xs: I,
+    f: F,
+    g: G,
+) -> Box<dyn Iterator<Item = (I::Item, J::Item, K::Item)> + 'a>
+where
+    I: Iterator + 'a,
+    J: Iterator + 'a,
+    K: Iterator + 'a,
+    F: Fn(&T, &I::Item) -> J + 'a,
+    G: Fn(&T, &I::Item, &J::Item) -> K + 'a,
+    T: 'a,
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    let data = Rc::new(data);
+    let xys =
+        exhaustive_dependent_pairs_indexed(data.clone(), xs, move |data: &Rc<T>, x: &I::Item| {
+            f(&**data, x)
+        });
+    Box::new(
+        exhaustive_dependent_pairs_indexed(
+            data,
+            xys,
+            move |data: &Rc<T>, xy: &(I::Item, J::Item)| g(&**data, &xy.0, &xy.1),
+        )
+        .map(|((x, y), z)| (x, y, z)),
+    )
+}
+
+pub fn exhaustive_dependent_quadruples<'a, I, J, K, L, F, G, H, T>(
+    data: T,
+    xs: I,
+    f: F,
+    g: G,
+    h: H,
+) -> Box<dyn Iterator<Item = (I::Item, J::Item, K::Item, L::Item)> + 'a>
+where
+    I: Iterator + 'a,
+    J: Iterator + 'a,
+    K: Iterator + 'a,
+    L: Iterator + 'a,
+    F: Fn(&T, &I::Item) -> J + 'a,
+    G: Fn(&T, &I::Item, &J::Item) -> K + 'a,
+    H: Fn(&T, &I::Item, &J::Item, &K::Item) -> L + 'a,
+    T: 'a,
+    I::Item: Clone,
+    J::Item: Clone,
+    K::Item: Clone,
+{
+    let data = Rc::new(data);
+    let xyzs = exhaustive_dependent_triples(
+        data.clone(),
+        xs,
+        move |data: &Rc<T>, x: &I::Item| f(&**data, x),
+        move |data: &Rc<T>, x: &I::Item, y: &J::Item| g(&**data, x, y),
+    );
+    Box::new(
+        exhaustive_dependent_pairs_indexed(
+            data,
+            xyzs,
+            move |data: &Rc<T>, xyz: &(I::Item, J::Item, K::Item)| {
+                h(&**data, &xyz.0, &xyz.1, &xyz.2)
+            },
+        )
+        .map(|((x, y, z), w)| (x, y, z, w)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::primitive_ints::{exhaustive_unsigned, range_increasing, RangeIncreasing};
+
+    fn ys(_: &(), &x: &u8) -> RangeIncreasing<u8> {
+        range_increasing(0, x)
+    }
+
+    fn infinite_ys(_: &(), &x: &u8) -> RangeIncreasing<u32> {
+        range_increasing(u32::from(x), u32::MAX)
+    }
+
+    #[test]
+    fn test_exhaustive_dependent_pairs_checkpoints() {
+        assert_resumes(
+            || exhaustive_dependent_pairs((), range_increasing(0u8, 6), ys),
+            8,
+        );
+        assert_resumes(
+            || exhaustive_dependent_pairs_log((), exhaustive_unsigned::<u8>(), infinite_ys),
+            100,
+        );
+        assert_resumes(
+            || exhaustive_dependent_pairs_indexed((), range_increasing(0u8, 6), ys),
+            8,
+        );
+        assert_resumes(
+            || exhaustive_dependent_pairs_indexed_log((), exhaustive_unsigned::<u8>(), infinite_ys),
+            100,
+        );
+        assert_resumes(
+            || exhaustive_dependent_pairs_infinite((), exhaustive_unsigned::<u8>(), infinite_ys),
+            100,
+        );
+        assert_resumes(
+            || {
+                exhaustive_dependent_pairs_infinite_log(
+                    (),
+                    exhaustive_unsigned::<u8>(),
+                    infinite_ys,
+                )
+            },
+            100,
+        );
+    }
+
+    #[test]
+    fn test_random_dependent_pairs_indexed() {
+        // The pairs end when xs ends.
+        assert_eq!(
+            random_dependent_pairs_indexed((), range_increasing(1u8, 3), ys).collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (3, 0)]
+        );
+        // Equal xs don't share their ys, and the xs need not be comparable.
+        struct X(u8);
+        let ys: Vec<u8> = random_dependent_pairs_indexed(
+            (),
+            vec![X(2), X(2), X(3), X(2)].into_iter(),
+            |_: &(), x: &X| range_increasing(0, x.0),
+        )
+        .map(|(_, y)| y)
+        .collect();
+        assert_eq!(ys, [0, 0, 0, 0]);
+    }
+}
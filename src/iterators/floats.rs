@@ -0,0 +1,256 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use rand::Rng;
+
+use iterators::common::Seed;
+use iterators::general::{random, Random};
+use iterators::naturals::limbs_special_random_up_to_bits_old;
+use iterators::rngs::{new_rng, SeededRng};
+
+// A mantissa with long runs of 0s and 1s, from the same block model as the special random
+// unsigned integers.
+fn special_random_mantissa<T: PrimitiveUnsigned, R: Rng>(rng: &mut R, bits: u64) -> T {
+    let limbs: Vec<u32> = limbs_special_random_up_to_bits_old(rng, bits);
+    T::from_other_type_slice(&limbs)
+}
+
+macro_rules! float_generators {
+    (
+        $f: ident,
+        $u: ident,
+        $mantissa_bits: expr,
+        $exponent_bits: expr,
+        $random_fn: ident,
+        $from_bits_struct: ident,
+        $from_bits_fn: ident,
+        $finite_struct: ident,
+        $finite_fn: ident,
+        $range_struct: ident,
+        $range_fn: ident,
+        $special_struct: ident,
+        $special_fn: ident
+    ) => {
+        // Uniform in [0, 1).
+        pub fn $random_fn(seed: &Seed) -> Random<$f> {
+            random(seed)
+        }
+
+        pub struct $from_bits_struct(Random<$u>);
+
+        impl Iterator for $from_bits_struct {
+            type Item = $f;
+
+            fn next(&mut self) -> Option<$f> {
+                self.0.next().map($f::from_bits)
+            }
+        }
+
+        resumable!([], $from_bits_struct, [0], []);
+
+        // Uniform over bit patterns, so that every finite value, infinity and NaN is equally
+        // likely.
+        pub fn $from_bits_fn(seed: &Seed) -> $from_bits_struct {
+            $from_bits_struct(random(seed))
+        }
+
+        pub struct $finite_struct($from_bits_struct);
+
+        impl Iterator for $finite_struct {
+            type Item = $f;
+
+            fn next(&mut self) -> Option<$f> {
+                loop {
+                    let x = self.0.next();
+                    if x.map_or(false, $f::is_finite) {
+                        return x;
+                    }
+                }
+            }
+        }
+
+        resumable!([], $finite_struct, [0], []);
+
+        // Uniform over the bit patterns of finite values.
+        pub fn $finite_fn(seed: &Seed) -> $finite_struct {
+            $finite_struct($from_bits_fn(seed))
+        }
+
+        pub struct $range_struct {
+            rng: SeededRng,
+            a: $f,
+            b: $f,
+        }
+
+        impl Iterator for $range_struct {
+            type Item = $f;
+
+            fn next(&mut self) -> Option<$f> {
+                let r: $f = self.rng.gen();
+                // Unlike a + (b - a) * r, this doesn't overflow when b - a is too large to
+                // represent. Rounding may still produce b, or a value just outside [a, b).
+                let x = self.a * (1.0 - r) + self.b * r;
+                Some(if x < self.a {
+                    self.a
+                } else if x >= self.b {
+                    // The largest value less than b
+                    if self.b > 0.0 {
+                        $f::from_bits(self.b.to_bits() - 1)
+                    } else if self.b < 0.0 {
+                        $f::from_bits(self.b.to_bits() + 1)
+                    } else {
+                        -$f::from_bits(1)
+                    }
+                } else {
+                    x
+                })
+            }
+        }
+
+        resumable!([], $range_struct, [rng], []);
+
+        // Uniform in [a, b). a and b must be finite, and a must be less than b.
+        pub fn $range_fn(seed: &Seed, a: $f, b: $f) -> $range_struct {
+            if !a.is_finite() || !b.is_finite() {
+                panic!("Cannot generate values in a range with an infinite or NaN endpoint.");
+            }
+            if a >= b {
+                panic!("a must be less than b. a: {}, b: {}", a, b);
+            }
+            $range_struct {
+                rng: new_rng(seed),
+                a,
+                b,
+            }
+        }
+
+        pub struct $special_struct {
+            rng: SeededRng,
+        }
+
+        impl Iterator for $special_struct {
+            type Item = $f;
+
+            fn next(&mut self) -> Option<$f> {
+                let max_exponent: $u = (1 << $exponent_bits) - 1;
+                let sign: $u = if self.rng.gen() {
+                    1 << ($mantissa_bits + $exponent_bits)
+                } else {
+                    0
+                };
+                let bits = match self.rng.gen_range(0, 8) {
+                    // ±0
+                    0 => 0,
+                    // ±infinity
+                    1 => max_exponent << $mantissa_bits,
+                    // NaN, with a payload from the block model; an empty payload becomes the
+                    // canonical quiet NaN
+                    2 => {
+                        let mantissa: $u = special_random_mantissa(&mut self.rng, $mantissa_bits);
+                        let mantissa = if mantissa == 0 {
+                            1 << ($mantissa_bits - 1)
+                        } else {
+                            mantissa
+                        };
+                        (max_exponent << $mantissa_bits) | mantissa
+                    }
+                    // A subnormal value
+                    3 => {
+                        let mantissa: $u = special_random_mantissa(&mut self.rng, $mantissa_bits);
+                        mantissa.max(1)
+                    }
+                    // A power of 2, or one of the values adjacent to it
+                    4 => {
+                        let power = self.rng.gen_range(1, max_exponent) << $mantissa_bits;
+                        match self.rng.gen_range(0, 3) {
+                            0 => power - 1,
+                            1 => power,
+                            _ => power + 1,
+                        }
+                    }
+                    // A normal value whose mantissa has long runs of 0s and 1s
+                    _ => {
+                        let mantissa: $u = special_random_mantissa(&mut self.rng, $mantissa_bits);
+                        (self.rng.gen_range(1, max_exponent) << $mantissa_bits) | mantissa
+                    }
+                };
+                Some($f::from_bits(sign | bits))
+            }
+        }
+
+        resumable!([], $special_struct, [rng], []);
+
+        // Emphasizes the values that tend to break numeric code: ±0, ±infinity, NaNs with varied
+        // payloads, subnormals, values at or adjacent to powers of 2, and mantissas with long runs
+        // of 0s and 1s. Every sign is equally likely.
+        pub fn $special_fn(seed: &Seed) -> $special_struct {
+            $special_struct { rng: new_rng(seed) }
+        }
+    };
+}
+
+float_generators!(
+    f32,
+    u32,
+    23,
+    8,
+    random_f32s,
+    RandomF32sFromBits,
+    random_f32s_from_bits,
+    RandomFiniteF32s,
+    random_finite_f32s,
+    RandomRangeF32s,
+    random_range_f32s,
+    SpecialRandomF32s,
+    special_random_f32s
+);
+float_generators!(
+    f64,
+    u64,
+    52,
+    11,
+    random_f64s,
+    RandomF64sFromBits,
+    random_f64s_from_bits,
+    RandomFiniteF64s,
+    random_finite_f64s,
+    RandomRangeF64s,
+    random_range_f64s,
+    SpecialRandomF64s,
+    special_random_f64s
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_range_floats() {
+        let seed = Seed::example();
+        for x in random_range_f64s(&seed, f64::MIN, f64::MAX).take(1000) {
+            assert!(x.is_finite() && x < f64::MAX);
+        }
+        for x in random_range_f32s(&seed, -1.0, 1.0).take(1000) {
+            assert!((-1.0..1.0).contains(&x));
+        }
+        // Ranges containing a single value
+        let a = f64::from_bits(1.0f64.to_bits() + 1);
+        for &(a, b) in &[
+            (1.0, a),
+            (-0.0, f64::from_bits(1)),
+            (-f64::from_bits(1), 0.0),
+        ] {
+            assert!(random_range_f64s(&seed, a, b).take(100).all(|x| x == a));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_range_floats_empty() {
+        random_range_f64s(&Seed::example(), 1.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_range_floats_reversed() {
+        random_range_f32s(&Seed::example(), 1.0, -1.0);
+    }
+}
@@ -1,13 +1,18 @@
 use std::iter::Peekable;
 use std::marker::PhantomData;
 
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
-use rand::{IsaacRng, Rand, Rng, SeedableRng};
+use rand::{Rand, Rng};
 
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
 use iterators::primitive_ints::{random_range, RandomRange};
+use iterators::rngs::{new_rng, SeededRng};
 
 pub struct Random<T: Rand> {
-    pub(crate) rng: Box<IsaacRng>,
+    pub(crate) rng: SeededRng,
     boo: PhantomData<*const T>,
 }
 
@@ -19,9 +24,11 @@ impl<T: Rand> Iterator for Random<T> {
     }
 }
 
-pub fn random<T: Rand>(seed: &[u32]) -> Random<T> {
+resumable!([T: Rand], Random<T>, [rng], []);
+
+pub fn random<T: Rand>(seed: &Seed) -> Random<T> {
     Random {
-        rng: Box::new(IsaacRng::from_seed(seed)),
+        rng: new_rng(seed),
         boo: PhantomData,
     }
 }
@@ -41,7 +48,9 @@ impl<T: Clone> Iterator for RandomFromVector<T> {
     }
 }
 
-pub fn random_from_vector<T>(seed: &[u32], xs: Vec<T>) -> RandomFromVector<T> {
+resumable!([T], RandomFromVector<T>, [range], []);
+
+pub fn random_from_vector<T>(seed: &Seed, xs: Vec<T>) -> RandomFromVector<T> {
     if xs.is_empty() {
         panic!("Cannot randomly generate values from an empty Vec.");
     }
@@ -52,13 +61,18 @@ pub fn random_from_vector<T>(seed: &[u32], xs: Vec<T>) -> RandomFromVector<T> {
     }
 }
 
+// Caches the values of xs so that they can be looked up by index. The values up to len have been
+// generated, as far as the users of the cache can tell; values beyond that may have been cached
+// by an iterator whose position was then restored to an earlier one.
 pub(crate) struct CachedIterator<I: Iterator>
 where
     I::Item: Clone,
 {
     xs: Peekable<I>,
     cache: Vec<I::Item>,
+    // Whether xs has no values left after the cached ones.
     done: bool,
+    len: usize,
 }
 
 impl<I: Iterator> Iterator for CachedIterator<I>
@@ -68,40 +82,114 @@ where
     type Item = I::Item;
 
     fn next(&mut self) -> Option<I::Item> {
+        if self.len == self.cache.len() && !self.cache_next() {
+            return None;
+        }
+        self.len += 1;
+        Some(self.cache[self.len - 1].clone())
+    }
+}
+
+impl<I: Iterator> CachedIterator<I>
+where
+    I::Item: Clone,
+{
+    // Caches one more value of xs, without generating it. Returns false if xs has run out.
+    fn cache_next(&mut self) -> bool {
         if self.done {
-            None
-        } else {
-            let ox = self.xs.next();
-            if let Some(ref x) = ox {
-                self.cache.push(x.clone());
+            return false;
+        }
+        match self.xs.next() {
+            Some(x) => {
+                self.cache.push(x);
                 if self.xs.peek().is_none() {
                     self.done = true;
                 }
-            } else {
+                true
+            }
+            None => {
                 self.done = true;
+                false
             }
-            ox
         }
     }
+
+    pub(crate) fn get(&mut self, index: usize) -> Option<I::Item> {
+        while self.len <= index {
+            self.next()?;
+        }
+        Some(self.cache[index].clone())
+    }
+
+    // Returns the length of the underlying iterator, if it is known to be finite and has been
+    // exhausted.
+    pub(crate) fn known_len(&self) -> Option<usize> {
+        if self.done && self.len == self.cache.len() {
+            Some(self.len)
+        } else {
+            None
+        }
+    }
+
+    // Returns the length of the underlying iterator if it is at most 2^bits. The values needed to
+    // find out are cached, but don't count as generated, so known_len is unaffected.
+    pub(crate) fn len_up_to_power_of_2(&mut self, bits: u64) -> Option<usize> {
+        let limit = if bits < usize::WIDTH {
+            usize::power_of_2(bits)
+        } else {
+            usize::MAX
+        };
+        while self.cache.len() < limit && self.cache_next() {}
+        if self.done && self.cache.len() <= limit {
+            Some(self.cache.len())
+        } else {
+            None
+        }
+    }
+
+    // Exhausts the underlying iterator, which must be finite, and returns its length.
+    pub(crate) fn total_len(&mut self) -> usize {
+        while self.next().is_some() {}
+        self.len
+    }
+
+    // The values generated so far.
+    pub(crate) fn generated(&self) -> &[I::Item] {
+        &self.cache[..self.len]
+    }
+
+    // Returns the index of the first generated value equal to x. Values that haven't been
+    // generated yet are not searched, so this terminates even if the underlying iterator is
+    // infinite.
+    pub(crate) fn position(&self, x: &I::Item) -> Option<usize>
+    where
+        I::Item: PartialEq,
+    {
+        self.cache[..self.len].iter().position(|y| y == x)
+    }
 }
 
-impl<I: Iterator> CachedIterator<I>
+// Only the number of values generated is saved. The values themselves are generated again, or
+// taken from the cache, when they are needed.
+impl<I: Iterator> Resumable for CachedIterator<I>
 where
     I::Item: Clone,
 {
-    pub(crate) fn get(&mut self, index: usize) -> Option<I::Item> {
-        let old_len = self.cache.len();
-        if index < old_len {
-            Some(self.cache[index].clone())
-        } else {
-            if self.done {
-                return None;
-            }
-            for _ in old_len..(index + 1) {
-                self.next()?;
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(u64::exact_from(self.len));
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        let len = state.read_usize(usize::MAX)?;
+        if len < self.len {
+            self.len = len;
+        }
+        while self.len < len {
+            if self.next().is_none() {
+                return Err(CheckpointError::InvalidValue(u64::exact_from(len)));
             }
-            Some(self.cache.last().unwrap().clone())
         }
+        Ok(())
     }
 }
 
@@ -114,6 +202,7 @@ where
             xs: xs.peekable(),
             cache: Vec::new(),
             done: false,
+            len: 0,
         }
     }
 }
@@ -0,0 +1,959 @@
+use std::any::type_name;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use rand::distributions::range::SampleRange;
+use rand::Rand;
+use std::hash::Hash;
+
+use iterators::arrays::{random_arrays, RandomArrays};
+use iterators::common::{Seed, StreamVersion};
+use iterators::dependent_pairs::{random_dependent_pairs_indexed, RandomDependentPairsIndexed};
+use iterators::floats::{
+    random_f32s, random_f32s_from_bits, random_f64s, random_f64s_from_bits, random_finite_f32s,
+    random_finite_f64s, random_range_f32s, random_range_f64s, special_random_f32s,
+    special_random_f64s, RandomF32sFromBits, RandomF64sFromBits, RandomFiniteF32s,
+    RandomFiniteF64s, RandomRangeF32s, RandomRangeF64s, SpecialRandomF32s, SpecialRandomF64s,
+};
+use iterators::general::{random, random_from_vector, Random, RandomFromVector};
+use iterators::integers::{
+    random_integers, random_natural_integers, random_negative_integers, random_nonzero_integers,
+    special_random_integers, special_random_natural_integers, special_random_negative_integers,
+    special_random_nonzero_integers, RandomIntegers, RandomNaturalIntegers, RandomNegativeIntegers,
+    RandomNonzeroIntegers, SpecialRandomIntegers, SpecialRandomNaturalIntegers,
+    SpecialRandomNegativeIntegers, SpecialRandomNonzeroIntegers,
+};
+use iterators::integers_geometric::{
+    i32s_geometric, range_up_geometric_u32, u32s_geometric, I32sGeometric, RangeUpGeometricU32,
+    U32sGeometric,
+};
+use iterators::naturals::{
+    random_naturals, random_positive_naturals, random_range_natural, special_random_naturals,
+    special_random_positive_naturals, special_random_range_natural, RandomNaturals,
+    RandomPositiveNaturals, RandomRangeNatural, SpecialRandomNaturals,
+    SpecialRandomPositiveNaturals, SpecialRandomRangeNatural,
+};
+use iterators::permutations::{random_permutations, RandomPermutations};
+use iterators::primitive_ints::{
+    random_natural_signed, random_negative_signed, random_nonzero_signed, random_positive_signed,
+    random_positive_unsigned, random_range, random_range_down, random_range_up,
+    special_random_natural_signed, special_random_negative_signed, special_random_nonzero_signed,
+    special_random_positive_signed, special_random_positive_unsigned, special_random_signed,
+    special_random_unsigned, RandomNaturalSigned, RandomNegativeSigned, RandomNonzeroSigned,
+    RandomPositiveSigned, RandomPositiveUnsigned, RandomRange, SpecialRandomNaturalSigned,
+    SpecialRandomNegativeSigned, SpecialRandomNonzeroSigned, SpecialRandomPositiveSigned,
+    SpecialRandomPositiveUnsigned, SpecialRandomSigned, SpecialRandomUnsigned,
+};
+use iterators::rounding_modes::random_rounding_modes;
+use iterators::sets::{
+    random_b_tree_sets, random_hash_sets, random_subsets, RandomBTreeSets, RandomHashSets,
+    RandomSubsets,
+};
+use iterators::tuples::{
+    random_octuples, random_pairs, random_quadruples, random_quintuples, random_septuples,
+    random_sextuples, random_triples, RandomOctuples, RandomPairs, RandomQuadruples,
+    RandomQuintuples, RandomSeptuples, RandomSextuples, RandomTriples,
+};
+use iterators::vecs::{
+    random_vecs, random_vecs_fixed_length, random_vecs_min_length, special_random_bool_vecs,
+    special_random_unsigned_vecs, special_random_unsigned_vecs_fixed_length,
+    special_random_unsigned_vecs_min_length, RandomVecs, RandomVecsFixedLength,
+    RandomVecsMinLength, SpecialRandomBoolVecs, SpecialRandomUnsignedVecs,
+    SpecialRandomUnsignedVecsFixedLength, SpecialRandomUnsignedVecsMinLength,
+};
+
+// Something that produces an iterator from a seed, such as random_naturals with a fixed scale.
+// Any Fn(&Seed) -> I is a Generator, so closures can be used wherever a Generator is expected, such
+// as in the combinator functions like random_vecs. Rust can't infer the argument type of a closure
+// that is only known to be a Generator, so such closures need it written out, as in
+// &|seed: &Seed| random_naturals(seed, 32).
+pub trait Generator {
+    type Iter: Iterator;
+
+    fn generate(&self, seed: &Seed) -> Self::Iter;
+
+    fn description(&self) -> String;
+
+    // The stream version this generator is pinned to. Generators that aren't pinned follow the
+    // version of the seed they're given.
+    fn version(&self) -> Option<StreamVersion> {
+        None
+    }
+}
+
+impl<I: Iterator, F: ?Sized> Generator for F
+where
+    F: Fn(&Seed) -> I,
+{
+    type Iter = I;
+
+    fn generate(&self, seed: &Seed) -> I {
+        self(seed)
+    }
+
+    fn description(&self) -> String {
+        "closure".to_string()
+    }
+}
+
+fn pin(seed: &Seed, version: Option<StreamVersion>) -> Seed {
+    match version {
+        Some(version) => seed.clone().with_version(version),
+        None => seed.clone(),
+    }
+}
+
+fn describe(fn_name: &str, types: &[&str], args: &[String]) -> String {
+    if types.is_empty() {
+        format!("{}({})", fn_name, args.join(", "))
+    } else {
+        format!("{}::<{}>({})", fn_name, types.join(", "), args.join(", "))
+    }
+}
+
+macro_rules! generator {
+    (
+        $gen_name: ident,
+        [$($t: ident),*],
+        $iter: ty,
+        $fn_name: ident,
+        [$($field: ident: $field_type: ty),*],
+        [$($bounds: tt)*]
+    ) => {
+        pub struct $gen_name<$($t),*> {
+            $(
+                $field: $field_type,
+            )*
+            version: Option<StreamVersion>,
+            boo: PhantomData<*const ($($t,)*)>,
+        }
+
+        impl<$($t),*> $gen_name<$($t),*> {
+            #[allow(unknown_lints, new_without_default)]
+            pub fn new($($field: $field_type),*) -> $gen_name<$($t),*> {
+                $gen_name {
+                    $(
+                        $field,
+                    )*
+                    version: None,
+                    boo: PhantomData,
+                }
+            }
+
+            pub fn with_version(mut self, version: StreamVersion) -> $gen_name<$($t),*> {
+                self.version = Some(version);
+                self
+            }
+        }
+
+        impl<$($t),*> Clone for $gen_name<$($t),*>
+        where
+            $($field_type: Clone),*
+        {
+            #[allow(unknown_lints, clone_on_copy)]
+            fn clone(&self) -> $gen_name<$($t),*> {
+                $gen_name {
+                    $(
+                        $field: self.$field.clone(),
+                    )*
+                    version: self.version,
+                    boo: PhantomData,
+                }
+            }
+        }
+
+        impl<$($t),*> Generator for $gen_name<$($t),*>
+        where
+            $($bounds)*
+            $($field_type: Clone + Debug),*
+        {
+            type Iter = $iter;
+
+            #[allow(unknown_lints, clone_on_copy)]
+            fn generate(&self, seed: &Seed) -> $iter {
+                $fn_name(&pin(seed, self.version) $(, self.$field.clone())*)
+            }
+
+            fn description(&self) -> String {
+                describe(
+                    stringify!($fn_name),
+                    &[$(type_name::<$t>()),*],
+                    &[$(format!("{} = {:?}", stringify!($field), self.$field)),*],
+                )
+            }
+
+            fn version(&self) -> Option<StreamVersion> {
+                self.version
+            }
+        }
+    };
+}
+
+generator!(RandomGenerator, [T], Random<T>, random, [], [T: Rand,]);
+generator!(
+    RandomFromVectorGenerator,
+    [T],
+    RandomFromVector<T>,
+    random_from_vector,
+    [xs: Vec<T>],
+    [T: Clone,]
+);
+
+generator!(
+    RandomPositiveUnsignedGenerator,
+    [T],
+    RandomPositiveUnsigned<T>,
+    random_positive_unsigned,
+    [],
+    [T: PrimitiveUnsigned + Rand,]
+);
+generator!(
+    RandomPositiveSignedGenerator,
+    [T],
+    RandomPositiveSigned<T>,
+    random_positive_signed,
+    [],
+    [T: PrimitiveSigned + Rand,]
+);
+generator!(
+    RandomNegativeSignedGenerator,
+    [T],
+    RandomNegativeSigned<T>,
+    random_negative_signed,
+    [],
+    [T: PrimitiveSigned + Rand,]
+);
+generator!(
+    RandomNaturalSignedGenerator,
+    [T],
+    RandomNaturalSigned<T>,
+    random_natural_signed,
+    [],
+    [T: PrimitiveSigned + Rand,]
+);
+generator!(
+    RandomNonzeroSignedGenerator,
+    [T],
+    RandomNonzeroSigned<T>,
+    random_nonzero_signed,
+    [],
+    [T: PrimitiveSigned + Rand,]
+);
+generator!(
+    RandomRangeGenerator,
+    [T],
+    RandomRange<T>,
+    random_range,
+    [a: T, b: T],
+    [T: PrimitiveInt + Rand + SampleRange,]
+);
+generator!(
+    RandomRangeUpGenerator,
+    [T],
+    RandomRange<T>,
+    random_range_up,
+    [a: T],
+    [T: PrimitiveInt + Rand + SampleRange,]
+);
+generator!(
+    RandomRangeDownGenerator,
+    [T],
+    RandomRange<T>,
+    random_range_down,
+    [a: T],
+    [T: PrimitiveInt + Rand + SampleRange,]
+);
+generator!(
+    SpecialRandomUnsignedGenerator,
+    [T],
+    SpecialRandomUnsigned<T>,
+    special_random_unsigned,
+    [],
+    [T: PrimitiveUnsigned + Rand,]
+);
+generator!(
+    SpecialRandomPositiveUnsignedGenerator,
+    [T],
+    SpecialRandomPositiveUnsigned<T>,
+    special_random_positive_unsigned,
+    [],
+    [T: PrimitiveUnsigned + Rand,]
+);
+generator!(
+    SpecialRandomNaturalSignedGenerator,
+    [T],
+    SpecialRandomNaturalSigned<T>,
+    special_random_natural_signed,
+    [],
+    [
+        T: PrimitiveSigned + WrappingFrom<<T as PrimitiveSigned>::UnsignedOfEqualWidth>,
+        <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
+    ]
+);
+generator!(
+    SpecialRandomPositiveSignedGenerator,
+    [T],
+    SpecialRandomPositiveSigned<T>,
+    special_random_positive_signed,
+    [],
+    [
+        T: PrimitiveSigned + WrappingFrom<<T as PrimitiveSigned>::UnsignedOfEqualWidth>,
+        <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
+    ]
+);
+generator!(
+    SpecialRandomNegativeSignedGenerator,
+    [T],
+    SpecialRandomNegativeSigned<T>,
+    special_random_negative_signed,
+    [],
+    [
+        T: PrimitiveSigned + WrappingFrom<<T as PrimitiveSigned>::UnsignedOfEqualWidth>,
+        <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
+    ]
+);
+generator!(
+    SpecialRandomSignedGenerator,
+    [T],
+    SpecialRandomSigned<T>,
+    special_random_signed,
+    [],
+    [
+        T: PrimitiveSigned + WrappingFrom<<T as PrimitiveSigned>::UnsignedOfEqualWidth>,
+        <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
+    ]
+);
+generator!(
+    SpecialRandomNonzeroSignedGenerator,
+    [T],
+    SpecialRandomNonzeroSigned<T>,
+    special_random_nonzero_signed,
+    [],
+    [
+        T: PrimitiveSigned + WrappingFrom<<T as PrimitiveSigned>::UnsignedOfEqualWidth>,
+        <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
+    ]
+);
+
+generator!(
+    U32sGeometricGenerator,
+    [],
+    U32sGeometric,
+    u32s_geometric,
+    [scale: u32],
+    []
+);
+generator!(
+    I32sGeometricGenerator,
+    [],
+    I32sGeometric,
+    i32s_geometric,
+    [scale: u32],
+    []
+);
+generator!(
+    RangeUpGeometricU32Generator,
+    [],
+    RangeUpGeometricU32,
+    range_up_geometric_u32,
+    [scale: u32, min: u32],
+    []
+);
+
+generator!(
+    RandomPositiveNaturalsGenerator,
+    [],
+    RandomPositiveNaturals,
+    random_positive_naturals,
+    [scale: u32],
+    []
+);
+generator!(
+    RandomNaturalsGenerator,
+    [],
+    RandomNaturals,
+    random_naturals,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomPositiveNaturalsGenerator,
+    [],
+    SpecialRandomPositiveNaturals,
+    special_random_positive_naturals,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomNaturalsGenerator,
+    [],
+    SpecialRandomNaturals,
+    special_random_naturals,
+    [scale: u32],
+    []
+);
+generator!(
+    RandomRangeNaturalGenerator,
+    [],
+    RandomRangeNatural,
+    random_range_natural,
+    [a: Natural, b: Natural],
+    []
+);
+generator!(
+    SpecialRandomRangeNaturalGenerator,
+    [],
+    SpecialRandomRangeNatural,
+    special_random_range_natural,
+    [a: Natural, b: Natural],
+    []
+);
+
+generator!(
+    RandomNaturalIntegersGenerator,
+    [],
+    RandomNaturalIntegers,
+    random_natural_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    RandomNegativeIntegersGenerator,
+    [],
+    RandomNegativeIntegers,
+    random_negative_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    RandomNonzeroIntegersGenerator,
+    [],
+    RandomNonzeroIntegers,
+    random_nonzero_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    RandomIntegersGenerator,
+    [],
+    RandomIntegers,
+    random_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomNaturalIntegersGenerator,
+    [],
+    SpecialRandomNaturalIntegers,
+    special_random_natural_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomNegativeIntegersGenerator,
+    [],
+    SpecialRandomNegativeIntegers,
+    special_random_negative_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomNonzeroIntegersGenerator,
+    [],
+    SpecialRandomNonzeroIntegers,
+    special_random_nonzero_integers,
+    [scale: u32],
+    []
+);
+generator!(
+    SpecialRandomIntegersGenerator,
+    [],
+    SpecialRandomIntegers,
+    special_random_integers,
+    [scale: u32],
+    []
+);
+
+generator!(RandomF32sGenerator, [], Random<f32>, random_f32s, [], []);
+generator!(RandomF64sGenerator, [], Random<f64>, random_f64s, [], []);
+generator!(
+    RandomF32sFromBitsGenerator,
+    [],
+    RandomF32sFromBits,
+    random_f32s_from_bits,
+    [],
+    []
+);
+generator!(
+    RandomF64sFromBitsGenerator,
+    [],
+    RandomF64sFromBits,
+    random_f64s_from_bits,
+    [],
+    []
+);
+generator!(
+    RandomFiniteF32sGenerator,
+    [],
+    RandomFiniteF32s,
+    random_finite_f32s,
+    [],
+    []
+);
+generator!(
+    RandomFiniteF64sGenerator,
+    [],
+    RandomFiniteF64s,
+    random_finite_f64s,
+    [],
+    []
+);
+generator!(
+    RandomRangeF32sGenerator,
+    [],
+    RandomRangeF32s,
+    random_range_f32s,
+    [a: f32, b: f32],
+    []
+);
+generator!(
+    RandomRangeF64sGenerator,
+    [],
+    RandomRangeF64s,
+    random_range_f64s,
+    [a: f64, b: f64],
+    []
+);
+generator!(
+    SpecialRandomF32sGenerator,
+    [],
+    SpecialRandomF32s,
+    special_random_f32s,
+    [],
+    []
+);
+generator!(
+    SpecialRandomF64sGenerator,
+    [],
+    SpecialRandomF64s,
+    special_random_f64s,
+    [],
+    []
+);
+
+generator!(
+    RandomRoundingModesGenerator,
+    [],
+    RandomFromVector<RoundingMode>,
+    random_rounding_modes,
+    [],
+    []
+);
+
+generator!(
+    SpecialRandomUnsignedVecsGenerator,
+    [T],
+    SpecialRandomUnsignedVecs<T>,
+    special_random_unsigned_vecs,
+    [scale: u32],
+    [T: PrimitiveUnsigned,]
+);
+generator!(
+    SpecialRandomUnsignedVecsMinLengthGenerator,
+    [T],
+    SpecialRandomUnsignedVecsMinLength<T>,
+    special_random_unsigned_vecs_min_length,
+    [scale: u32, min_length: u64],
+    [T: PrimitiveUnsigned,]
+);
+generator!(
+    SpecialRandomUnsignedVecsFixedLengthGenerator,
+    [T],
+    SpecialRandomUnsignedVecsFixedLength<T>,
+    special_random_unsigned_vecs_fixed_length,
+    [length: u64],
+    [T: PrimitiveUnsigned,]
+);
+generator!(
+    SpecialRandomBoolVecsGenerator,
+    [],
+    SpecialRandomBoolVecs,
+    special_random_bool_vecs,
+    [scale: u32],
+    []
+);
+
+generator!(
+    RandomPermutationsGenerator,
+    [T],
+    RandomPermutations<T>,
+    random_permutations,
+    [xs: Vec<T>],
+    [T: Clone,]
+);
+
+#[derive(Clone, Debug)]
+pub struct RandomSubsetsGenerator<T: Clone> {
+    scale: u32,
+    xs: Vec<T>,
+    version: Option<StreamVersion>,
+}
+
+impl<T: Clone> RandomSubsetsGenerator<T> {
+    pub fn new(scale: u32, xs: Vec<T>) -> RandomSubsetsGenerator<T> {
+        RandomSubsetsGenerator {
+            scale,
+            xs,
+            version: None,
+        }
+    }
+
+    pub fn with_version(mut self, version: StreamVersion) -> RandomSubsetsGenerator<T> {
+        self.version = Some(version);
+        self
+    }
+}
+
+impl<T: Clone + Debug> Generator for RandomSubsetsGenerator<T> {
+    type Iter = RandomSubsets<T>;
+
+    fn generate(&self, seed: &Seed) -> RandomSubsets<T> {
+        random_subsets(
+            &pin(seed, self.version),
+            self.scale,
+            self.xs.clone().into_iter(),
+        )
+    }
+
+    fn description(&self) -> String {
+        describe(
+            "random_subsets",
+            &[],
+            &[
+                format!("scale = {:?}", self.scale),
+                format!("xs = {:?}", self.xs),
+            ],
+        )
+    }
+
+    fn version(&self) -> Option<StreamVersion> {
+        self.version
+    }
+}
+
+// A generator built from other generators, such as random_vecs with a generator of elements.
+macro_rules! combinator_generator {
+    (
+        $gen_name: ident,
+        $iter: ident,
+        $fn_name: ident,
+        [$($field: ident: $field_type: ty),*],
+        [$($item_bounds: tt)*],
+        $(
+            [$gen_type: ident, $gen: ident]
+        ),*
+    ) => {
+        #[derive(Clone)]
+        pub struct $gen_name<$($gen_type: Generator),*> {
+            $(
+                $field: $field_type,
+            )*
+            $(
+                $gen: $gen_type,
+            )*
+            version: Option<StreamVersion>,
+        }
+
+        impl<$($gen_type: Generator),*> $gen_name<$($gen_type),*> {
+            pub fn new(
+                $($field: $field_type,)*
+                $($gen: $gen_type),*
+            ) -> $gen_name<$($gen_type),*> {
+                $gen_name {
+                    $(
+                        $field,
+                    )*
+                    $(
+                        $gen,
+                    )*
+                    version: None,
+                }
+            }
+
+            pub fn with_version(mut self, version: StreamVersion) -> $gen_name<$($gen_type),*> {
+                self.version = Some(version);
+                self
+            }
+        }
+
+        impl<$($gen_type: Generator),*> Generator for $gen_name<$($gen_type),*>
+        where
+            $($item_bounds)*
+        {
+            type Iter = $iter<$($gen_type::Iter),*>;
+
+            fn generate(&self, seed: &Seed) -> $iter<$($gen_type::Iter),*> {
+                $fn_name(&pin(seed, self.version), $(self.$field,)* $(&self.$gen),*)
+            }
+
+            fn description(&self) -> String {
+                describe(
+                    stringify!($fn_name),
+                    &[],
+                    &[
+                        $(
+                            format!("{} = {:?}", stringify!($field), self.$field),
+                        )*
+                        $(
+                            format!("{} = {}", stringify!($gen), self.$gen.description()),
+                        )*
+                    ],
+                )
+            }
+
+            fn version(&self) -> Option<StreamVersion> {
+                self.version
+            }
+        }
+    };
+}
+
+combinator_generator!(
+    RandomVecsGenerator,
+    RandomVecs,
+    random_vecs,
+    [scale: u32],
+    [],
+    [G, xs]
+);
+combinator_generator!(
+    RandomVecsMinLengthGenerator,
+    RandomVecsMinLength,
+    random_vecs_min_length,
+    [scale: u32, min_length: u64],
+    [],
+    [G, xs]
+);
+combinator_generator!(
+    RandomVecsFixedLengthGenerator,
+    RandomVecsFixedLength,
+    random_vecs_fixed_length,
+    [length: u64],
+    [],
+    [G, xs]
+);
+combinator_generator!(
+    RandomBTreeSetsGenerator,
+    RandomBTreeSets,
+    random_b_tree_sets,
+    [scale: u32],
+    [<G::Iter as Iterator>::Item: Ord,],
+    [G, xs]
+);
+combinator_generator!(
+    RandomHashSetsGenerator,
+    RandomHashSets,
+    random_hash_sets,
+    [scale: u32],
+    [<G::Iter as Iterator>::Item: Eq + Hash,],
+    [G, xs]
+);
+combinator_generator!(
+    RandomPairsGenerator,
+    RandomPairs,
+    random_pairs,
+    [],
+    [],
+    [G, xs],
+    [H, ys]
+);
+combinator_generator!(
+    RandomTriplesGenerator,
+    RandomTriples,
+    random_triples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs]
+);
+combinator_generator!(
+    RandomQuadruplesGenerator,
+    RandomQuadruples,
+    random_quadruples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs],
+    [J, ws]
+);
+combinator_generator!(
+    RandomQuintuplesGenerator,
+    RandomQuintuples,
+    random_quintuples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs],
+    [J, ws],
+    [K, vs]
+);
+combinator_generator!(
+    RandomSextuplesGenerator,
+    RandomSextuples,
+    random_sextuples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs],
+    [J, ws],
+    [K, vs],
+    [L, us]
+);
+combinator_generator!(
+    RandomSeptuplesGenerator,
+    RandomSeptuples,
+    random_septuples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs],
+    [J, ws],
+    [K, vs],
+    [L, us],
+    [M, ts]
+);
+combinator_generator!(
+    RandomOctuplesGenerator,
+    RandomOctuples,
+    random_octuples,
+    [],
+    [],
+    [G, xs],
+    [H, ys],
+    [I, zs],
+    [J, ws],
+    [K, vs],
+    [L, us],
+    [M, ts],
+    [N, ss]
+);
+
+#[derive(Clone)]
+pub struct RandomArraysGenerator<G: Generator, const N: usize> {
+    xs: G,
+    version: Option<StreamVersion>,
+}
+
+impl<G: Generator, const N: usize> RandomArraysGenerator<G, N> {
+    pub fn new(xs: G) -> RandomArraysGenerator<G, N> {
+        RandomArraysGenerator { xs, version: None }
+    }
+
+    pub fn with_version(mut self, version: StreamVersion) -> RandomArraysGenerator<G, N> {
+        self.version = Some(version);
+        self
+    }
+}
+
+impl<G: Generator, const N: usize> Generator for RandomArraysGenerator<G, N> {
+    type Iter = RandomArrays<G::Iter, N>;
+
+    fn generate(&self, seed: &Seed) -> RandomArrays<G::Iter, N> {
+        random_arrays(&pin(seed, self.version), &self.xs)
+    }
+
+    fn description(&self) -> String {
+        describe(
+            "random_arrays",
+            &[&N.to_string()],
+            &[format!("xs = {}", self.xs.description())],
+        )
+    }
+
+    fn version(&self) -> Option<StreamVersion> {
+        self.version
+    }
+}
+
+// Generates random_dependent_pairs_indexed with the xs from a generator. f maps a seed and an x to
+// the ys of x. The seed is the same for every x, so f should derive a seed from it and x, e.g.
+// with seed.derive(&format!("{:?}", x)); since the ys aren't shared, equal xs then get equal ys.
+pub struct RandomDependentPairsIndexedGenerator<G: Generator, J, F> {
+    xs: G,
+    f: F,
+    version: Option<StreamVersion>,
+    boo: PhantomData<*const J>,
+}
+
+impl<G: Generator, J, F> RandomDependentPairsIndexedGenerator<G, J, F> {
+    pub fn new(xs: G, f: F) -> RandomDependentPairsIndexedGenerator<G, J, F> {
+        RandomDependentPairsIndexedGenerator {
+            xs,
+            f,
+            version: None,
+            boo: PhantomData,
+        }
+    }
+
+    pub fn with_version(
+        mut self,
+        version: StreamVersion,
+    ) -> RandomDependentPairsIndexedGenerator<G, J, F> {
+        self.version = Some(version);
+        self
+    }
+}
+
+impl<G: Generator + Clone, J, F: Clone> Clone for RandomDependentPairsIndexedGenerator<G, J, F> {
+    fn clone(&self) -> RandomDependentPairsIndexedGenerator<G, J, F> {
+        RandomDependentPairsIndexedGenerator {
+            xs: self.xs.clone(),
+            f: self.f.clone(),
+            version: self.version,
+            boo: PhantomData,
+        }
+    }
+}
+
+impl<G: Generator, J: Iterator, F: Clone> Generator
+    for RandomDependentPairsIndexedGenerator<G, J, F>
+where
+    F: Fn(&Seed, &<G::Iter as Iterator>::Item) -> J,
+{
+    type Iter = RandomDependentPairsIndexed<G::Iter, J, F, Seed>;
+
+    fn generate(&self, seed: &Seed) -> RandomDependentPairsIndexed<G::Iter, J, F, Seed> {
+        let seed = pin(seed, self.version);
+        random_dependent_pairs_indexed(
+            seed.derive("ys"),
+            self.xs.generate(&seed.derive("xs")),
+            self.f.clone(),
+        )
+    }
+
+    fn description(&self) -> String {
+        describe(
+            "random_dependent_pairs_indexed",
+            &[],
+            &[
+                format!("xs = {}", self.xs.description()),
+                "f = closure".to_string(),
+            ],
+        )
+    }
+
+    fn version(&self) -> Option<StreamVersion> {
+        self.version
+    }
+}
@@ -1,18 +1,22 @@
 use std::cmp::Ordering;
 
-use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::CheckedFrom;
+use malachite_base::num::logic::traits::BitAccess;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use rand::{IsaacRng, Rng};
+use rand::Rng;
 
-use iterators::common::scramble;
+use iterators::common::Seed;
 use iterators::general::{random, Random};
 use iterators::integers_geometric::RangeUpGeometricU32;
 use iterators::naturals::{
-    random_natural_below_old, random_natural_with_bits_old, random_naturals,
-    random_positive_naturals, special_random_naturals, special_random_positive_naturals,
-    RandomNaturals, RandomPositiveNaturals, SpecialRandomNaturals, SpecialRandomPositiveNaturals,
+    exhaustive_naturals, exhaustive_positive_naturals, random_natural_below_old,
+    random_natural_with_bits_old, random_naturals, random_positive_naturals,
+    special_random_naturals, special_random_positive_naturals, ExhaustiveNaturals, RandomNaturals,
+    RandomPositiveNaturals, SpecialRandomNaturals, SpecialRandomPositiveNaturals,
 };
+use iterators::rngs::SeededRng;
 
 struct RandomPositiveIntegers(RandomPositiveNaturals);
 
@@ -24,7 +28,9 @@ impl Iterator for RandomPositiveIntegers {
     }
 }
 
-fn random_positive_integers(seed: &[u32], scale: u32) -> RandomPositiveIntegers {
+resumable!([], RandomPositiveIntegers, [0], []);
+
+fn random_positive_integers(seed: &Seed, scale: u32) -> RandomPositiveIntegers {
     RandomPositiveIntegers(random_positive_naturals(seed, scale))
 }
 
@@ -38,7 +44,9 @@ impl Iterator for RandomNaturalIntegers {
     }
 }
 
-pub fn random_natural_integers(seed: &[u32], scale: u32) -> RandomNaturalIntegers {
+resumable!([], RandomNaturalIntegers, [0], []);
+
+pub fn random_natural_integers(seed: &Seed, scale: u32) -> RandomNaturalIntegers {
     RandomNaturalIntegers(random_naturals(seed, scale))
 }
 
@@ -52,7 +60,9 @@ impl Iterator for RandomNegativeIntegers {
     }
 }
 
-pub fn random_negative_integers(seed: &[u32], scale: u32) -> RandomNegativeIntegers {
+resumable!([], RandomNegativeIntegers, [0], []);
+
+pub fn random_negative_integers(seed: &Seed, scale: u32) -> RandomNegativeIntegers {
     RandomNegativeIntegers(random_positive_integers(seed, scale))
 }
 
@@ -73,10 +83,12 @@ impl Iterator for RandomNonzeroIntegers {
     }
 }
 
-pub fn random_nonzero_integers(seed: &[u32], scale: u32) -> RandomNonzeroIntegers {
+resumable!([], RandomNonzeroIntegers, [signs, abs], []);
+
+pub fn random_nonzero_integers(seed: &Seed, scale: u32) -> RandomNonzeroIntegers {
     RandomNonzeroIntegers {
-        signs: random(&scramble(seed, "signs")),
-        abs: random_positive_integers(&scramble(seed, "abs"), scale),
+        signs: random(&seed.derive("signs")),
+        abs: random_positive_integers(&seed.derive("abs"), scale),
     }
 }
 
@@ -97,10 +109,12 @@ impl Iterator for RandomIntegers {
     }
 }
 
-pub fn random_integers(seed: &[u32], scale: u32) -> RandomIntegers {
+resumable!([], RandomIntegers, [signs, abs], []);
+
+pub fn random_integers(seed: &Seed, scale: u32) -> RandomIntegers {
     RandomIntegers {
-        signs: random(&scramble(seed, "signs")),
-        abs: random_natural_integers(&scramble(seed, "abs"), scale),
+        signs: random(&seed.derive("signs")),
+        abs: random_natural_integers(&seed.derive("abs"), scale),
     }
 }
 
@@ -114,7 +128,9 @@ impl Iterator for SpecialRandomPositiveIntegers {
     }
 }
 
-fn special_random_positive_integers(seed: &[u32], scale: u32) -> SpecialRandomPositiveIntegers {
+resumable!([], SpecialRandomPositiveIntegers, [0], []);
+
+fn special_random_positive_integers(seed: &Seed, scale: u32) -> SpecialRandomPositiveIntegers {
     SpecialRandomPositiveIntegers(special_random_positive_naturals(seed, scale))
 }
 
@@ -128,7 +144,9 @@ impl Iterator for SpecialRandomNaturalIntegers {
     }
 }
 
-pub fn special_random_natural_integers(seed: &[u32], scale: u32) -> SpecialRandomNaturalIntegers {
+resumable!([], SpecialRandomNaturalIntegers, [0], []);
+
+pub fn special_random_natural_integers(seed: &Seed, scale: u32) -> SpecialRandomNaturalIntegers {
     SpecialRandomNaturalIntegers(special_random_naturals(seed, scale))
 }
 
@@ -142,7 +160,9 @@ impl Iterator for SpecialRandomNegativeIntegers {
     }
 }
 
-pub fn special_random_negative_integers(seed: &[u32], scale: u32) -> SpecialRandomNegativeIntegers {
+resumable!([], SpecialRandomNegativeIntegers, [0], []);
+
+pub fn special_random_negative_integers(seed: &Seed, scale: u32) -> SpecialRandomNegativeIntegers {
     SpecialRandomNegativeIntegers(special_random_positive_integers(seed, scale))
 }
 
@@ -163,10 +183,12 @@ impl Iterator for SpecialRandomNonzeroIntegers {
     }
 }
 
-pub fn special_random_nonzero_integers(seed: &[u32], scale: u32) -> SpecialRandomNonzeroIntegers {
+resumable!([], SpecialRandomNonzeroIntegers, [signs, abs], []);
+
+pub fn special_random_nonzero_integers(seed: &Seed, scale: u32) -> SpecialRandomNonzeroIntegers {
     SpecialRandomNonzeroIntegers {
-        signs: random(&scramble(seed, "signs")),
-        abs: special_random_positive_integers(&scramble(seed, "abs"), scale),
+        signs: random(&seed.derive("signs")),
+        abs: special_random_positive_integers(&seed.derive("abs"), scale),
     }
 }
 
@@ -187,15 +209,17 @@ impl Iterator for SpecialRandomIntegers {
     }
 }
 
-pub fn special_random_integers(seed: &[u32], scale: u32) -> SpecialRandomIntegers {
+resumable!([], SpecialRandomIntegers, [signs, abs], []);
+
+pub fn special_random_integers(seed: &Seed, scale: u32) -> SpecialRandomIntegers {
     SpecialRandomIntegers {
-        signs: random(&scramble(seed, "signs")),
-        abs: special_random_natural_integers(&scramble(seed, "abs"), scale),
+        signs: random(&seed.derive("signs")),
+        abs: special_random_natural_integers(&seed.derive("abs"), scale),
     }
 }
 
 struct RandomRangeUpInteger {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     bit_sizes: RangeUpGeometricU32,
     a: Integer,
     a_bit_size: u64,
@@ -255,3 +279,152 @@ impl Iterator for RandomRangeUpInteger {
         })
     }
 }
+
+// The index of x in the order 0, 1, -1, 2, -2, ..., or None if it doesn't fit in a u64.
+fn integer_index(x: &Integer) -> Option<u64> {
+    match x.cmp(&Integer::ZERO) {
+        Ordering::Equal => Some(0),
+        Ordering::Greater => u64::checked_from(x)?.checked_mul(2).map(|i| i - 1),
+        Ordering::Less => u64::checked_from(&-x)?.checked_mul(2),
+    }
+}
+
+// The inverse of integer_index.
+fn integer_from_index(i: Natural) -> Integer {
+    if i.get_bit(0) {
+        Integer::from((i >> 1u64) + Natural::ONE)
+    } else {
+        -Integer::from(i >> 1u64)
+    }
+}
+
+struct ExhaustivePositiveIntegers(ExhaustiveNaturals);
+
+impl Iterator for ExhaustivePositiveIntegers {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        self.0.next().map(Integer::from)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Integer> {
+        self.0.nth(n).map(Integer::from)
+    }
+}
+
+resumable!([], ExhaustivePositiveIntegers, [0], []);
+
+fn exhaustive_positive_integers() -> ExhaustivePositiveIntegers {
+    ExhaustivePositiveIntegers(exhaustive_positive_naturals())
+}
+
+pub struct ExhaustiveNaturalIntegers(ExhaustiveNaturals);
+
+impl Iterator for ExhaustiveNaturalIntegers {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        self.0.next().map(Integer::from)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Integer> {
+        self.0.nth(n).map(Integer::from)
+    }
+}
+
+impl ExhaustiveNaturalIntegers {
+    pub fn index_of(&self, x: &Integer) -> Option<u64> {
+        u64::checked_from(x)
+    }
+}
+
+resumable!([], ExhaustiveNaturalIntegers, [0], []);
+
+pub fn exhaustive_natural_integers() -> ExhaustiveNaturalIntegers {
+    ExhaustiveNaturalIntegers(exhaustive_naturals())
+}
+
+pub struct ExhaustiveNegativeIntegers(ExhaustivePositiveIntegers);
+
+impl Iterator for ExhaustiveNegativeIntegers {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        self.0.next().map(|i| -i)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Integer> {
+        self.0.nth(n).map(|i| -i)
+    }
+}
+
+impl ExhaustiveNegativeIntegers {
+    pub fn index_of(&self, x: &Integer) -> Option<u64> {
+        if *x < Integer::ZERO {
+            u64::checked_from(&-x).map(|i| i - 1)
+        } else {
+            None
+        }
+    }
+}
+
+resumable!([], ExhaustiveNegativeIntegers, [0], []);
+
+pub fn exhaustive_negative_integers() -> ExhaustiveNegativeIntegers {
+    ExhaustiveNegativeIntegers(exhaustive_positive_integers())
+}
+
+// Generates 1, -1, 2, -2, .... The wrapped iterator generates the indices of these values in the
+// order generated by exhaustive_integers.
+pub struct ExhaustiveNonzeroIntegers(ExhaustiveNaturals);
+
+impl Iterator for ExhaustiveNonzeroIntegers {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        self.0.next().map(integer_from_index)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Integer> {
+        self.0.nth(n).map(integer_from_index)
+    }
+}
+
+impl ExhaustiveNonzeroIntegers {
+    pub fn index_of(&self, x: &Integer) -> Option<u64> {
+        integer_index(x)?.checked_sub(1)
+    }
+}
+
+resumable!([], ExhaustiveNonzeroIntegers, [0], []);
+
+pub fn exhaustive_nonzero_integers() -> ExhaustiveNonzeroIntegers {
+    ExhaustiveNonzeroIntegers(exhaustive_positive_naturals())
+}
+
+// Generates 0, 1, -1, 2, -2, .... The wrapped iterator generates the indices of these values.
+pub struct ExhaustiveIntegers(ExhaustiveNaturals);
+
+impl Iterator for ExhaustiveIntegers {
+    type Item = Integer;
+
+    fn next(&mut self) -> Option<Integer> {
+        self.0.next().map(integer_from_index)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Integer> {
+        self.0.nth(n).map(integer_from_index)
+    }
+}
+
+impl ExhaustiveIntegers {
+    pub fn index_of(&self, x: &Integer) -> Option<u64> {
+        integer_index(x)
+    }
+}
+
+resumable!([], ExhaustiveIntegers, [0], []);
+
+pub fn exhaustive_integers() -> ExhaustiveIntegers {
+    ExhaustiveIntegers(exhaustive_naturals())
+}
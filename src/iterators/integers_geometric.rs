@@ -1,11 +1,12 @@
 use malachite_base::num::conversion::traits::WrappingFrom;
-use rand::{IsaacRng, Rng, SeedableRng};
+use rand::Rng;
 
-use iterators::common::scramble;
+use iterators::common::Seed;
 use iterators::general::{random, Random};
+use iterators::rngs::{new_rng, SeededRng};
 
 pub(crate) struct PositiveU32sGeometric {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     weight: u32,
 }
 
@@ -21,15 +22,17 @@ impl Iterator for PositiveU32sGeometric {
     }
 }
 
-pub(crate) fn positive_u32s_geometric(seed: &[u32], scale: u32) -> PositiveU32sGeometric {
+resumable!([], PositiveU32sGeometric, [rng], []);
+
+pub(crate) fn positive_u32s_geometric(seed: &Seed, scale: u32) -> PositiveU32sGeometric {
     PositiveU32sGeometric {
-        rng: Box::new(IsaacRng::from_seed(seed)),
+        rng: new_rng(seed),
         weight: scale + 2,
     }
 }
 
 pub struct U32sGeometric {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     weight: u32,
 }
 
@@ -45,9 +48,11 @@ impl Iterator for U32sGeometric {
     }
 }
 
-pub fn u32s_geometric(seed: &[u32], scale: u32) -> U32sGeometric {
+resumable!([], U32sGeometric, [rng], []);
+
+pub fn u32s_geometric(seed: &Seed, scale: u32) -> U32sGeometric {
     U32sGeometric {
-        rng: Box::new(IsaacRng::from_seed(seed)),
+        rng: new_rng(seed),
         weight: scale + 2,
     }
 }
@@ -69,10 +74,12 @@ impl Iterator for I32sGeometric {
     }
 }
 
-pub fn i32s_geometric(seed: &[u32], scale: u32) -> I32sGeometric {
+resumable!([], I32sGeometric, [signs, abs], []);
+
+pub fn i32s_geometric(seed: &Seed, scale: u32) -> I32sGeometric {
     I32sGeometric {
-        signs: random(&scramble(seed, "signs")),
-        abs: u32s_geometric(&scramble(seed, "abs"), scale),
+        signs: random(&seed.derive("signs")),
+        abs: u32s_geometric(&seed.derive("abs"), scale),
     }
 }
 
@@ -89,7 +96,9 @@ impl Iterator for RangeUpGeometricU32 {
     }
 }
 
-pub fn range_up_geometric_u32(seed: &[u32], scale: u32, min: u32) -> RangeUpGeometricU32 {
+resumable!([], RangeUpGeometricU32, [naturals], []);
+
+pub fn range_up_geometric_u32(seed: &Seed, scale: u32, min: u32) -> RangeUpGeometricU32 {
     RangeUpGeometricU32 {
         naturals: u32s_geometric(seed, scale),
         min,
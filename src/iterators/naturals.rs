@@ -2,21 +2,21 @@ use malachite_base::num::arithmetic::traits::{
     IsPowerOf2, ModPowerOf2, SaturatingSubAssign, ShrRound,
 };
 use malachite_base::num::basic::traits::{One, Zero};
-use malachite_base::num::conversion::traits::ExactFrom;
-#[cfg(not(feature = "32_bit_limbs"))]
-use malachite_base::num::conversion::traits::VecFromOtherTypeSlice;
+use malachite_base::num::conversion::traits::{CheckedFrom, ExactFrom, VecFromOtherTypeSlice};
 use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_nz::natural::arithmetic::add::limbs_slice_add_limb_in_place;
 use malachite_nz::natural::Natural;
 use rand::distributions::{IndependentSample, Range};
-use rand::{IsaacRng, Rand, Rng, SeedableRng};
+use rand::{Rand, Rng};
 use std::cmp::max;
 
-use iterators::common::scramble;
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
 use iterators::integers_geometric::{
     positive_u32s_geometric, u32s_geometric, PositiveU32sGeometric, U32sGeometric,
 };
+use iterators::rngs::{new_rng, SeededRng};
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 
 pub(crate) fn random_natural_below_old<R: Rng>(rng: &mut R, n: &Natural) -> Natural {
@@ -167,7 +167,7 @@ pub(crate) fn special_random_natural_with_bits_old<R: Rng>(rng: &mut R, bits: u6
 }
 
 pub struct RandomPositiveNaturals {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     bit_sizes: PositiveU32sGeometric,
 }
 
@@ -182,15 +182,17 @@ impl Iterator for RandomPositiveNaturals {
     }
 }
 
-pub fn random_positive_naturals(seed: &[u32], scale: u32) -> RandomPositiveNaturals {
+resumable!([], RandomPositiveNaturals, [rng, bit_sizes], []);
+
+pub fn random_positive_naturals(seed: &Seed, scale: u32) -> RandomPositiveNaturals {
     RandomPositiveNaturals {
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "bits"))),
-        bit_sizes: positive_u32s_geometric(&scramble(seed, "bitsizes"), scale),
+        rng: new_rng(&seed.derive("bits")),
+        bit_sizes: positive_u32s_geometric(&seed.derive("bitsizes"), scale),
     }
 }
 
 pub struct RandomNaturals {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     bit_sizes: U32sGeometric,
 }
 
@@ -205,15 +207,17 @@ impl Iterator for RandomNaturals {
     }
 }
 
-pub fn random_naturals(seed: &[u32], scale: u32) -> RandomNaturals {
+resumable!([], RandomNaturals, [rng, bit_sizes], []);
+
+pub fn random_naturals(seed: &Seed, scale: u32) -> RandomNaturals {
     RandomNaturals {
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "bits"))),
-        bit_sizes: u32s_geometric(&scramble(seed, "bitsizes"), scale),
+        rng: new_rng(&seed.derive("bits")),
+        bit_sizes: u32s_geometric(&seed.derive("bitsizes"), scale),
     }
 }
 
 pub struct SpecialRandomPositiveNaturals {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     bit_sizes: PositiveU32sGeometric,
 }
 
@@ -228,15 +232,17 @@ impl Iterator for SpecialRandomPositiveNaturals {
     }
 }
 
-pub fn special_random_positive_naturals(seed: &[u32], scale: u32) -> SpecialRandomPositiveNaturals {
+resumable!([], SpecialRandomPositiveNaturals, [rng, bit_sizes], []);
+
+pub fn special_random_positive_naturals(seed: &Seed, scale: u32) -> SpecialRandomPositiveNaturals {
     SpecialRandomPositiveNaturals {
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "bits"))),
-        bit_sizes: positive_u32s_geometric(&scramble(seed, "bitsizes"), scale),
+        rng: new_rng(&seed.derive("bits")),
+        bit_sizes: positive_u32s_geometric(&seed.derive("bitsizes"), scale),
     }
 }
 
 pub struct SpecialRandomNaturals {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     bit_sizes: U32sGeometric,
 }
 
@@ -251,15 +257,17 @@ impl Iterator for SpecialRandomNaturals {
     }
 }
 
-pub fn special_random_naturals(seed: &[u32], scale: u32) -> SpecialRandomNaturals {
+resumable!([], SpecialRandomNaturals, [rng, bit_sizes], []);
+
+pub fn special_random_naturals(seed: &Seed, scale: u32) -> SpecialRandomNaturals {
     SpecialRandomNaturals {
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "bits"))),
-        bit_sizes: u32s_geometric(&scramble(seed, "bitsizes"), scale),
+        rng: new_rng(&seed.derive("bits")),
+        bit_sizes: u32s_geometric(&seed.derive("bitsizes"), scale),
     }
 }
 
 pub struct RandomRangeNatural {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     diameter_plus_one: Natural,
     a: Natural,
 }
@@ -272,19 +280,21 @@ impl Iterator for RandomRangeNatural {
     }
 }
 
-pub fn random_range_natural(seed: &[u32], a: Natural, b: Natural) -> RandomRangeNatural {
+resumable!([], RandomRangeNatural, [rng], []);
+
+pub fn random_range_natural(seed: &Seed, a: Natural, b: Natural) -> RandomRangeNatural {
     if a > b {
         panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
     }
     RandomRangeNatural {
-        rng: Box::new(IsaacRng::from_seed(seed)),
+        rng: new_rng(seed),
         diameter_plus_one: b - &a + Natural::ONE,
         a,
     }
 }
 
 pub struct SpecialRandomRangeNatural {
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
     diameter_plus_one: Natural,
     a: Natural,
 }
@@ -297,8 +307,10 @@ impl Iterator for SpecialRandomRangeNatural {
     }
 }
 
+resumable!([], SpecialRandomRangeNatural, [rng], []);
+
 pub fn special_random_range_natural(
-    seed: &[u32],
+    seed: &Seed,
     a: Natural,
     b: Natural,
 ) -> SpecialRandomRangeNatural {
@@ -306,8 +318,113 @@ pub fn special_random_range_natural(
         panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
     }
     SpecialRandomRangeNatural {
-        rng: Box::new(IsaacRng::from_seed(seed)),
+        rng: new_rng(seed),
         diameter_plus_one: b - &a + Natural::ONE,
         a,
     }
 }
+
+pub struct ExhaustiveNaturals {
+    start: Natural,
+    i: Natural,
+}
+
+impl Iterator for ExhaustiveNaturals {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        let n = self.i.clone();
+        self.i += Natural::ONE;
+        Some(n)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Natural> {
+        self.i += Natural::from(u64::exact_from(n));
+        self.next()
+    }
+}
+
+impl ExhaustiveNaturals {
+    pub fn index_of(&self, x: &Natural) -> Option<u64> {
+        if *x >= self.start {
+            u64::checked_from(&(x - &self.start))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "32_bit_limbs")]
+fn natural_to_u64s(n: &Natural) -> Vec<u64> {
+    u64::vec_from_other_type_slice(&n.to_limbs_asc())
+}
+
+#[cfg(not(feature = "32_bit_limbs"))]
+fn natural_to_u64s(n: &Natural) -> Vec<u64> {
+    n.to_limbs_asc()
+}
+
+#[cfg(feature = "32_bit_limbs")]
+fn natural_from_u64s(xs: Vec<u64>) -> Natural {
+    Natural::from_owned_limbs_asc(u32::vec_from_other_type_slice(&xs))
+}
+
+#[cfg(not(feature = "32_bit_limbs"))]
+fn natural_from_u64s(xs: Vec<u64>) -> Natural {
+    Natural::from_owned_limbs_asc(xs)
+}
+
+// The state is the current value, as a length followed by 64-bit words.
+impl Resumable for ExhaustiveNaturals {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        let xs = natural_to_u64s(&self.i);
+        state.push(u64::exact_from(xs.len()));
+        state.extend(xs);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        let len = state.read()?;
+        let xs = (0..len)
+            .map(|_| state.read())
+            .collect::<Result<Vec<u64>, _>>()?;
+        self.i = natural_from_u64s(xs);
+        Ok(())
+    }
+}
+
+pub fn exhaustive_naturals() -> ExhaustiveNaturals {
+    ExhaustiveNaturals {
+        start: Natural::ZERO,
+        i: Natural::ZERO,
+    }
+}
+
+pub fn exhaustive_positive_naturals() -> ExhaustiveNaturals {
+    ExhaustiveNaturals {
+        start: Natural::ONE,
+        i: Natural::ONE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::tuples::tests::assert_nth;
+
+    #[test]
+    fn test_exhaustive_naturals_index_of() {
+        assert_nth(exhaustive_positive_naturals, 100);
+        for (i, x) in exhaustive_naturals().take(100).enumerate() {
+            assert_eq!(exhaustive_naturals().index_of(&x), Some(u64::exact_from(i)));
+        }
+        let mut xs = exhaustive_positive_naturals();
+        xs.nth(10);
+        assert_eq!(xs.index_of(&Natural::ONE), Some(0));
+        assert_eq!(xs.index_of(&Natural::ZERO), None);
+        assert_eq!(xs.index_of(&Natural::from(u64::MAX)), Some(u64::MAX - 1));
+        assert_eq!(
+            exhaustive_naturals().index_of(&(Natural::from(u64::MAX) + Natural::ONE)),
+            None
+        );
+    }
+}
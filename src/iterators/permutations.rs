@@ -0,0 +1,172 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use rand::Rng;
+
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
+use iterators::rngs::{new_rng, SeededRng};
+
+fn shuffle_with_rng<T, R: Rng>(rng: &mut R, xs: &mut [T]) {
+    // Fisher-Yates
+    for i in (1..xs.len()).rev() {
+        let j = rng.gen_range(0, i + 1);
+        xs.swap(i, j);
+    }
+}
+
+pub fn shuffle<T>(seed: &Seed, xs: &mut [T]) {
+    shuffle_with_rng(&mut new_rng(seed), xs);
+}
+
+pub struct RandomPermutations<T: Clone> {
+    xs: Vec<T>,
+    rng: SeededRng,
+}
+
+impl<T: Clone> Iterator for RandomPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let mut xs = self.xs.clone();
+        shuffle_with_rng(&mut self.rng, &mut xs);
+        Some(xs)
+    }
+}
+
+resumable!([T: Clone], RandomPermutations<T>, [rng], []);
+
+pub fn random_permutations<T: Clone>(seed: &Seed, xs: Vec<T>) -> RandomPermutations<T> {
+    RandomPermutations {
+        xs,
+        rng: new_rng(seed),
+    }
+}
+
+pub struct ExhaustivePermutations<T: Clone> {
+    xs: Vec<T>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for ExhaustivePermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let p = self.indices.iter().map(|&i| self.xs[i].clone()).collect();
+        // Advance the indices to the next permutation in lexicographic order.
+        let len = self.indices.len();
+        match (1..len)
+            .rev()
+            .find(|&i| self.indices[i - 1] < self.indices[i])
+        {
+            None => self.done = true,
+            Some(i) => {
+                let pivot = self.indices[i - 1];
+                let j = (i..len).rev().find(|&j| self.indices[j] > pivot).unwrap();
+                self.indices.swap(i - 1, j);
+                self.indices[i..].reverse();
+            }
+        }
+        Some(p)
+    }
+}
+
+impl<T: Clone> Resumable for ExhaustivePermutations<T> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.extend(self.indices.iter().map(|&i| u64::exact_from(i)));
+        self.done.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        let len = self.indices.len();
+        for i in &mut self.indices {
+            let j = state.read()?;
+            if j >= u64::exact_from(len) {
+                return Err(CheckpointError::InvalidValue(j));
+            }
+            *i = usize::exact_from(j);
+        }
+        self.done.restore_state(state)
+    }
+}
+
+// Generates the permutations of xs in lexicographic order of the positions of the elements, so
+// xs itself comes first. Equal elements are treated as distinct.
+pub fn exhaustive_permutations<T: Clone>(xs: Vec<T>) -> ExhaustivePermutations<T> {
+    ExhaustivePermutations {
+        indices: (0..xs.len()).collect(),
+        xs,
+        done: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::checkpoints::Checkpoint;
+    use std::collections::HashSet;
+
+    fn sorted<T: Clone + Ord>(xs: &[T]) -> Vec<T> {
+        let mut xs = xs.to_vec();
+        xs.sort();
+        xs
+    }
+
+    #[test]
+    fn test_exhaustive_permutations() {
+        let mut factorial = 1;
+        for n in 0..6 {
+            if n != 0 {
+                factorial *= n;
+            }
+            let ps: Vec<Vec<u8>> = exhaustive_permutations((0..n as u8).collect()).collect();
+            assert_eq!(ps.len(), factorial);
+            assert!(ps.windows(2).all(|w| w[0] < w[1]));
+            assert!(ps
+                .iter()
+                .all(|p| sorted(p) == (0..n as u8).collect::<Vec<_>>()));
+        }
+        let ps: Vec<String> = exhaustive_permutations(vec!['a', 'b', 'c'])
+            .map(|p| p.into_iter().collect())
+            .collect();
+        assert_eq!(ps, ["abc", "acb", "bac", "bca", "cab", "cba"]);
+        // Equal elements are treated as distinct.
+        assert_eq!(exhaustive_permutations(vec![1, 1, 2]).count(), 6);
+    }
+
+    #[test]
+    fn test_random_permutations() {
+        let xs = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let ps: Vec<Vec<u32>> = random_permutations(&Seed::example(), xs.clone())
+            .take(100)
+            .collect();
+        assert!(ps.iter().all(|p| sorted(p) == sorted(&xs)));
+        assert!(ps.iter().collect::<HashSet<_>>().len() > 1);
+
+        let mut ys = xs.clone();
+        shuffle(&Seed::example(), &mut ys);
+        assert_eq!(sorted(&ys), sorted(&xs));
+        let mut zs = xs.clone();
+        shuffle(&Seed::example(), &mut zs);
+        assert_eq!(ys, zs);
+    }
+
+    #[test]
+    fn test_permutations_checkpoints() {
+        assert_resumes(|| exhaustive_permutations(vec![1, 2, 3, 4, 5]), 50);
+        assert_resumes(|| exhaustive_permutations(vec![1, 2, 3]), 6);
+        assert_resumes(
+            || random_permutations(&Seed::example(), vec![1, 2, 3, 4, 5]),
+            50,
+        );
+
+        let mut xs = exhaustive_permutations(vec![1, 2, 3]);
+        assert_eq!(
+            xs.resume(&Checkpoint(vec![0, 3, 1, 0])),
+            Err(CheckpointError::InvalidValue(3))
+        );
+    }
+}
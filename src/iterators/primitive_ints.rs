@@ -1,16 +1,21 @@
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
-use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::conversion::traits::{CheckedFrom, ExactFrom, WrappingFrom, WrappingInto};
+use malachite_base::num::logic::traits::LowMask;
 use rand::distributions::range::SampleRange;
 use rand::distributions::{IndependentSample, Range};
-use rand::{IsaacRng, Rand, SeedableRng};
+use rand::Rand;
 
+use iterators::adaptors::{interleaved_index, Interleave};
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
 use iterators::general::{random, Random};
 use iterators::naturals::limbs_special_random_up_to_bits_old;
+use iterators::rngs::{new_rng, SeededRng};
 
 pub enum RandomRange<T: Rand> {
-    Some(bool, Box<IsaacRng>, Range<T>),
+    Some(bool, SeededRng, Range<T>),
     All(Random<T>),
 }
 
@@ -29,6 +34,22 @@ impl<T: PrimitiveInt + Rand + SampleRange> Iterator for RandomRange<T> {
     }
 }
 
+impl<T: Rand> Resumable for RandomRange<T> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        match *self {
+            RandomRange::Some(_, ref rng, _) => rng.save_state(state),
+            RandomRange::All(ref xs) => xs.save_state(state),
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        match *self {
+            RandomRange::Some(_, ref mut rng, _) => rng.restore_state(state),
+            RandomRange::All(ref mut xs) => xs.restore_state(state),
+        }
+    }
+}
+
 pub struct RandomPositiveUnsigned<T: Rand>(Random<T>);
 
 impl<T: PrimitiveUnsigned + Rand> Iterator for RandomPositiveUnsigned<T> {
@@ -44,7 +65,9 @@ impl<T: PrimitiveUnsigned + Rand> Iterator for RandomPositiveUnsigned<T> {
     }
 }
 
-pub fn random_positive_unsigned<T: Rand>(seed: &[u32]) -> RandomPositiveUnsigned<T> {
+resumable!([T: Rand], RandomPositiveUnsigned<T>, [0], []);
+
+pub fn random_positive_unsigned<T: Rand>(seed: &Seed) -> RandomPositiveUnsigned<T> {
     RandomPositiveUnsigned(random(seed))
 }
 
@@ -63,7 +86,9 @@ impl<T: PrimitiveSigned + Rand> Iterator for RandomPositiveSigned<T> {
     }
 }
 
-pub fn random_positive_signed<T: PrimitiveSigned + Rand>(seed: &[u32]) -> RandomPositiveSigned<T> {
+resumable!([T: PrimitiveSigned + Rand], RandomPositiveSigned<T>, [0], []);
+
+pub fn random_positive_signed<T: PrimitiveSigned + Rand>(seed: &Seed) -> RandomPositiveSigned<T> {
     RandomPositiveSigned(random(seed))
 }
 
@@ -77,7 +102,9 @@ impl<T: PrimitiveSigned + Rand> Iterator for RandomNegativeSigned<T> {
     }
 }
 
-pub fn random_negative_signed<T: PrimitiveSigned + Rand>(seed: &[u32]) -> RandomNegativeSigned<T> {
+resumable!([T: PrimitiveSigned + Rand], RandomNegativeSigned<T>, [0], []);
+
+pub fn random_negative_signed<T: PrimitiveSigned + Rand>(seed: &Seed) -> RandomNegativeSigned<T> {
     RandomNegativeSigned(random(seed))
 }
 
@@ -91,7 +118,9 @@ impl<T: PrimitiveSigned + Rand> Iterator for RandomNaturalSigned<T> {
     }
 }
 
-pub fn random_natural_signed<T: PrimitiveSigned + Rand>(seed: &[u32]) -> RandomNaturalSigned<T> {
+resumable!([T: PrimitiveSigned + Rand], RandomNaturalSigned<T>, [0], []);
+
+pub fn random_natural_signed<T: PrimitiveSigned + Rand>(seed: &Seed) -> RandomNaturalSigned<T> {
     RandomNaturalSigned(random(seed))
 }
 
@@ -110,38 +139,32 @@ impl<T: PrimitiveSigned + Rand> Iterator for RandomNonzeroSigned<T> {
     }
 }
 
-pub fn random_nonzero_signed<T: PrimitiveSigned + Rand>(seed: &[u32]) -> RandomNonzeroSigned<T> {
+resumable!([T: PrimitiveSigned + Rand], RandomNonzeroSigned<T>, [0], []);
+
+pub fn random_nonzero_signed<T: PrimitiveSigned + Rand>(seed: &Seed) -> RandomNonzeroSigned<T> {
     RandomNonzeroSigned(random(seed))
 }
 
 pub fn random_range<T: PrimitiveInt + Rand + SampleRange>(
-    seed: &[u32],
+    seed: &Seed,
     a: T,
     b: T,
 ) -> RandomRange<T> {
     if a == T::MIN && b == T::MAX {
         RandomRange::All(random(seed))
     } else if b == T::MAX {
-        RandomRange::Some(
-            true,
-            Box::new(IsaacRng::from_seed(seed)),
-            Range::new(a - T::ONE, b),
-        )
+        RandomRange::Some(true, new_rng(seed), Range::new(a - T::ONE, b))
     } else {
-        RandomRange::Some(
-            false,
-            Box::new(IsaacRng::from_seed(seed)),
-            Range::new(a, b + T::ONE),
-        )
+        RandomRange::Some(false, new_rng(seed), Range::new(a, b + T::ONE))
     }
 }
 
-pub fn random_range_up<T: PrimitiveInt + Rand + SampleRange>(seed: &[u32], a: T) -> RandomRange<T> {
+pub fn random_range_up<T: PrimitiveInt + Rand + SampleRange>(seed: &Seed, a: T) -> RandomRange<T> {
     random_range(seed, a, T::MAX)
 }
 
 pub fn random_range_down<T: PrimitiveInt + Rand + SampleRange>(
-    seed: &[u32],
+    seed: &Seed,
     a: T,
 ) -> RandomRange<T> {
     random_range(seed, T::MIN, a)
@@ -158,8 +181,10 @@ impl<T: PrimitiveUnsigned + Rand> Iterator for SpecialRandomUnsigned<T> {
     }
 }
 
+resumable!([T: PrimitiveUnsigned + Rand], SpecialRandomUnsigned<T>, [0], []);
+
 pub fn special_random_unsigned<T: PrimitiveUnsigned + Rand>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomUnsigned<T> {
     SpecialRandomUnsigned(random(seed))
 }
@@ -179,8 +204,10 @@ impl<T: PrimitiveUnsigned + Rand> Iterator for SpecialRandomPositiveUnsigned<T>
     }
 }
 
+resumable!([T: PrimitiveUnsigned + Rand], SpecialRandomPositiveUnsigned<T>, [0], []);
+
 pub fn special_random_positive_unsigned<T: PrimitiveUnsigned + Rand>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomPositiveUnsigned<T> {
     SpecialRandomPositiveUnsigned(special_random_unsigned(seed))
 }
@@ -203,8 +230,15 @@ where
     }
 }
 
+resumable!(
+    [T: PrimitiveSigned],
+    SpecialRandomNaturalSigned<T>,
+    [0],
+    [<T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,]
+);
+
 pub fn special_random_natural_signed<T: PrimitiveSigned>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomNaturalSigned<T>
 where
     <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
@@ -235,8 +269,15 @@ where
     }
 }
 
+resumable!(
+    [T: PrimitiveSigned],
+    SpecialRandomPositiveSigned<T>,
+    [0],
+    [<T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,]
+);
+
 pub fn special_random_positive_signed<T: PrimitiveSigned>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomPositiveSigned<T>
 where
     <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
@@ -262,8 +303,15 @@ where
     }
 }
 
+resumable!(
+    [T: PrimitiveSigned],
+    SpecialRandomNegativeSigned<T>,
+    [0],
+    [<T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,]
+);
+
 pub fn special_random_negative_signed<T: PrimitiveSigned>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomNegativeSigned<T>
 where
     <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
@@ -287,7 +335,14 @@ where
     }
 }
 
-pub fn special_random_signed<T: PrimitiveSigned>(seed: &[u32]) -> SpecialRandomSigned<T>
+resumable!(
+    [T: PrimitiveSigned],
+    SpecialRandomSigned<T>,
+    [0],
+    [<T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,]
+);
+
+pub fn special_random_signed<T: PrimitiveSigned>(seed: &Seed) -> SpecialRandomSigned<T>
 where
     <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
 {
@@ -315,11 +370,438 @@ where
     }
 }
 
+resumable!(
+    [T: PrimitiveSigned],
+    SpecialRandomNonzeroSigned<T>,
+    [0],
+    [<T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,]
+);
+
 pub fn special_random_nonzero_signed<T: PrimitiveSigned>(
-    seed: &[u32],
+    seed: &Seed,
 ) -> SpecialRandomNonzeroSigned<T>
 where
     <T as PrimitiveSigned>::UnsignedOfEqualWidth: Rand,
 {
     SpecialRandomNonzeroSigned(special_random_signed(seed))
 }
+
+// Returns b - a, where a <= b, or None if it doesn't fit in a u64.
+fn range_distance<T: PrimitiveInt>(a: T, b: T) -> Option<u64> {
+    let d = b.wrapping_sub(a);
+    if T::WIDTH <= u64::WIDTH {
+        Some(WrappingInto::<u64>::wrapping_into(d) & u64::low_mask(T::WIDTH))
+    } else if d >> u64::WIDTH == T::ZERO {
+        Some(WrappingInto::<u64>::wrapping_into(d))
+    } else {
+        None
+    }
+}
+
+// Returns the number of values in [a, b], where a <= b, or None if it doesn't fit in a u64.
+fn range_count<T: PrimitiveInt>(a: T, b: T) -> Option<u64> {
+    range_distance(a, b).and_then(|d| d.checked_add(1))
+}
+
+fn range_size_hint(count: Option<u64>) -> (usize, Option<usize>) {
+    match count.and_then(usize::checked_from) {
+        Some(count) => (count, Some(count)),
+        None => (usize::MAX, None),
+    }
+}
+
+pub struct RangeIncreasing<T: PrimitiveInt> {
+    a: T,
+    i: T,
+    b: T,
+    done: bool,
+}
+
+impl<T: PrimitiveInt> Iterator for RangeIncreasing<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            None
+        } else {
+            let x = self.i;
+            if x == self.b {
+                self.done = true;
+            } else {
+                self.i = x + T::ONE;
+            }
+            Some(x)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            range_size_hint(range_count(self.i, self.b))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let n = u64::exact_from(n);
+        if n > range_distance(self.i, self.b).unwrap_or(u64::MAX) {
+            self.i = self.b;
+            self.done = true;
+            return None;
+        }
+        self.i = self.i.wrapping_add(T::wrapping_from(n));
+        self.next()
+    }
+}
+
+impl<T: PrimitiveInt> RangeIncreasing<T> {
+    fn len_from_start(&self) -> Option<u64> {
+        if self.a > self.b {
+            Some(0)
+        } else {
+            range_count(self.a, self.b)
+        }
+    }
+
+    pub fn index_of(&self, x: T) -> Option<u64> {
+        if self.a <= x && x <= self.b {
+            range_distance(self.a, x)
+        } else {
+            None
+        }
+    }
+}
+
+// The state is the number of values generated so far, so for 128-bit types a checkpoint can only
+// be saved within the first 2^64 values.
+impl<T: PrimitiveInt> Resumable for RangeIncreasing<T> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(range_distance(self.a, self.i).expect("too many values to save the position"));
+        self.done.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        let offset = state.read()?;
+        if self.a <= self.b && offset > range_distance(self.a, self.b).unwrap_or(u64::MAX) {
+            return Err(CheckpointError::InvalidValue(offset));
+        }
+        self.i = self.a.wrapping_add(T::wrapping_from(offset));
+        self.done.restore_state(state)
+    }
+}
+
+pub struct RangeDecreasing<T: PrimitiveInt> {
+    a: T,
+    i: T,
+    b: T,
+    done: bool,
+}
+
+impl<T: PrimitiveInt> Iterator for RangeDecreasing<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            None
+        } else {
+            let x = self.i;
+            if x == self.a {
+                self.done = true;
+            } else {
+                self.i = x - T::ONE;
+            }
+            Some(x)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            range_size_hint(range_count(self.a, self.i))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let n = u64::exact_from(n);
+        if n > range_distance(self.a, self.i).unwrap_or(u64::MAX) {
+            self.i = self.a;
+            self.done = true;
+            return None;
+        }
+        self.i = self.i.wrapping_sub(T::wrapping_from(n));
+        self.next()
+    }
+}
+
+impl<T: PrimitiveInt> RangeDecreasing<T> {
+    fn len_from_start(&self) -> Option<u64> {
+        if self.a > self.b {
+            Some(0)
+        } else {
+            range_count(self.a, self.b)
+        }
+    }
+
+    pub fn index_of(&self, x: T) -> Option<u64> {
+        if self.a <= x && x <= self.b {
+            range_distance(x, self.b)
+        } else {
+            None
+        }
+    }
+}
+
+// Like RangeIncreasing, the state is the number of values generated so far.
+impl<T: PrimitiveInt> Resumable for RangeDecreasing<T> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(range_distance(self.i, self.b).expect("too many values to save the position"));
+        self.done.save_state(state);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        let offset = state.read()?;
+        if self.a <= self.b && offset > range_distance(self.a, self.b).unwrap_or(u64::MAX) {
+            return Err(CheckpointError::InvalidValue(offset));
+        }
+        self.i = self.b.wrapping_sub(T::wrapping_from(offset));
+        self.done.restore_state(state)
+    }
+}
+
+pub fn range_increasing<T: PrimitiveInt>(a: T, b: T) -> RangeIncreasing<T> {
+    RangeIncreasing {
+        a,
+        i: a,
+        b,
+        done: a > b,
+    }
+}
+
+pub fn range_decreasing<T: PrimitiveInt>(a: T, b: T) -> RangeDecreasing<T> {
+    RangeDecreasing {
+        a,
+        i: b,
+        b,
+        done: a > b,
+    }
+}
+
+pub fn range_up_increasing<T: PrimitiveInt>(a: T) -> RangeIncreasing<T> {
+    range_increasing(a, T::MAX)
+}
+
+pub fn range_down_decreasing<T: PrimitiveInt>(a: T) -> RangeDecreasing<T> {
+    range_decreasing(T::MIN, a)
+}
+
+pub fn exhaustive_unsigned<T: PrimitiveUnsigned>() -> RangeIncreasing<T> {
+    range_up_increasing(T::ZERO)
+}
+
+pub fn exhaustive_positive<T: PrimitiveInt>() -> RangeIncreasing<T> {
+    range_up_increasing(T::ONE)
+}
+
+pub fn exhaustive_natural_signed<T: PrimitiveSigned>() -> RangeIncreasing<T> {
+    range_up_increasing(T::ZERO)
+}
+
+pub fn exhaustive_negative_signed<T: PrimitiveSigned>() -> RangeDecreasing<T> {
+    range_down_decreasing(T::ZERO - T::ONE)
+}
+
+pub fn exhaustive_nonzero_signed<T: PrimitiveSigned>(
+) -> Interleave<RangeIncreasing<T>, RangeDecreasing<T>> {
+    Interleave::new(exhaustive_positive(), exhaustive_negative_signed())
+}
+
+impl<T: PrimitiveInt> Interleave<RangeIncreasing<T>, RangeDecreasing<T>> {
+    pub fn index_of(&self, x: T) -> Option<u64> {
+        match self.xs.index_of(x) {
+            Some(i) => interleaved_index(i, true, self.ys.len_from_start()),
+            None => interleaved_index(self.ys.index_of(x)?, false, self.xs.len_from_start()),
+        }
+    }
+}
+
+pub enum ExhaustiveRange<T: PrimitiveInt> {
+    AllNonNegative(RangeIncreasing<T>),
+    AllNonPositive(RangeDecreasing<T>),
+    // The bool is true until 0 has been generated.
+    SomeOfEachSign(bool, Interleave<RangeIncreasing<T>, RangeDecreasing<T>>),
+}
+
+impl<T: PrimitiveInt> Iterator for ExhaustiveRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref mut xs) => xs.next(),
+            ExhaustiveRange::AllNonPositive(ref mut xs) => xs.next(),
+            ExhaustiveRange::SomeOfEachSign(ref mut zero_next, ref mut xs) => {
+                if *zero_next {
+                    *zero_next = false;
+                    Some(T::ZERO)
+                } else {
+                    xs.next()
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref xs) => xs.size_hint(),
+            ExhaustiveRange::AllNonPositive(ref xs) => xs.size_hint(),
+            ExhaustiveRange::SomeOfEachSign(zero_next, ref xs) => {
+                let (lower, upper) = xs.size_hint();
+                let zero = usize::from(zero_next);
+                (
+                    lower.saturating_add(zero),
+                    upper.and_then(|upper| upper.checked_add(zero)),
+                )
+            }
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref mut xs) => xs.nth(n),
+            ExhaustiveRange::AllNonPositive(ref mut xs) => xs.nth(n),
+            ExhaustiveRange::SomeOfEachSign(ref mut zero_next, ref mut xs) => {
+                if !*zero_next {
+                    xs.nth(n)
+                } else if n == 0 {
+                    *zero_next = false;
+                    Some(T::ZERO)
+                } else {
+                    *zero_next = false;
+                    xs.nth(n - 1)
+                }
+            }
+        }
+    }
+}
+
+impl<T: PrimitiveInt> ExhaustiveRange<T> {
+    pub fn index_of(&self, x: T) -> Option<u64> {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref xs) => xs.index_of(x),
+            ExhaustiveRange::AllNonPositive(ref xs) => xs.index_of(x),
+            ExhaustiveRange::SomeOfEachSign(_, ref xs) => {
+                if x == T::ZERO {
+                    Some(0)
+                } else {
+                    xs.index_of(x)?.checked_add(1)
+                }
+            }
+        }
+    }
+}
+
+// Which variant is used only depends on a and b, so only the state of the variant is saved.
+impl<T: PrimitiveInt> Resumable for ExhaustiveRange<T> {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref xs) => xs.save_state(state),
+            ExhaustiveRange::AllNonPositive(ref xs) => xs.save_state(state),
+            ExhaustiveRange::SomeOfEachSign(zero_next, ref xs) => {
+                zero_next.save_state(state);
+                xs.save_state(state);
+            }
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        match *self {
+            ExhaustiveRange::AllNonNegative(ref mut xs) => xs.restore_state(state),
+            ExhaustiveRange::AllNonPositive(ref mut xs) => xs.restore_state(state),
+            ExhaustiveRange::SomeOfEachSign(ref mut zero_next, ref mut xs) => {
+                zero_next.restore_state(state)?;
+                xs.restore_state(state)
+            }
+        }
+    }
+}
+
+// Generates the values in [a, b] in order of increasing absolute value, with positive values
+// preceding negative values of the same absolute value.
+pub fn exhaustive_range<T: PrimitiveInt>(a: T, b: T) -> ExhaustiveRange<T> {
+    if a >= T::ZERO {
+        ExhaustiveRange::AllNonNegative(range_increasing(a, b))
+    } else if b <= T::ZERO {
+        ExhaustiveRange::AllNonPositive(range_decreasing(a, b))
+    } else {
+        ExhaustiveRange::SomeOfEachSign(
+            true,
+            Interleave::new(
+                range_increasing(T::ONE, b),
+                range_decreasing(a, T::ZERO - T::ONE),
+            ),
+        )
+    }
+}
+
+pub fn exhaustive_signed<T: PrimitiveSigned>() -> ExhaustiveRange<T> {
+    exhaustive_range(T::MIN, T::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::tuples::tests::assert_nth;
+
+    #[test]
+    fn test_exhaustive_primitive_ints_nth_and_index_of() {
+        assert_nth(|| range_increasing(3u8, 200), 300);
+        assert_nth(|| range_decreasing(-100i8, 100), 300);
+        assert_nth(exhaustive_unsigned::<u64>, 300);
+        assert_nth(exhaustive_nonzero_signed::<i8>, 300);
+        assert_nth(|| exhaustive_range(-3i16, 200), 300);
+        assert_nth(|| exhaustive_range(-200i16, 3), 300);
+        assert_nth(exhaustive_signed::<i128>, 300);
+        assert_eq!(exhaustive_signed::<i64>().nth(usize::MAX), Some(i64::MIN));
+        assert_eq!(
+            exhaustive_signed::<i64>().nth(usize::MAX - 2),
+            Some(i64::MAX)
+        );
+
+        for (i, x) in range_increasing(3u8, 200).enumerate() {
+            assert_eq!(
+                range_increasing(3u8, 200).index_of(x),
+                Some(u64::exact_from(i))
+            );
+        }
+        for (i, x) in range_decreasing(-100i8, 100).enumerate() {
+            assert_eq!(
+                range_decreasing(-100i8, 100).index_of(x),
+                Some(u64::exact_from(i))
+            );
+        }
+        for (i, x) in exhaustive_signed::<i8>().enumerate() {
+            assert_eq!(
+                exhaustive_signed::<i8>().index_of(x),
+                Some(u64::exact_from(i))
+            );
+        }
+        for &(a, b) in &[(-3i16, 200), (-200, 3), (-5, -1), (0, 9)] {
+            for (i, x) in exhaustive_range(a, b).enumerate() {
+                assert_eq!(exhaustive_range(a, b).index_of(x), Some(u64::exact_from(i)));
+            }
+            assert_eq!(exhaustive_range(a, b).index_of(b + 1), None);
+        }
+        assert_eq!(
+            exhaustive_signed::<i64>().index_of(i64::MIN),
+            Some(u64::MAX)
+        );
+        assert_eq!(exhaustive_signed::<i128>().index_of(i128::MIN), None);
+        assert_eq!(range_increasing(3u8, 200).index_of(2), None);
+    }
+}
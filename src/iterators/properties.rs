@@ -0,0 +1,220 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use iterators::common::Seed;
+use iterators::generators::Generator;
+use iterators::rngs::RecordingBackend;
+use iterators::shrinkers::{draws_shrinker, minimize, Shrinker};
+
+// The first value that made a property fail, with what is needed to reproduce it: the value is
+// generator.generate(&seed).nth(index). Values from an exhaustive iterator have no seed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Counterexample<T> {
+    pub value: T,
+    pub index: usize,
+    // The value after shrinking, if a shrinker was given.
+    pub shrunk: Option<T>,
+    pub seed: Option<Seed>,
+    pub generator: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PropertyReport<T> {
+    // exhausted is true if the values ran out before the sample budget did, in which case the
+    // property holds for every value.
+    Passed { samples: usize, exhausted: bool },
+    Failed(Box<Counterexample<T>>),
+}
+
+impl<T> PropertyReport<T> {
+    pub fn passed(&self) -> bool {
+        match *self {
+            PropertyReport::Passed { .. } => true,
+            PropertyReport::Failed(_) => false,
+        }
+    }
+
+    pub fn counterexample(&self) -> Option<&Counterexample<T>> {
+        match *self {
+            PropertyReport::Passed { .. } => None,
+            PropertyReport::Failed(ref c) => Some(c),
+        }
+    }
+}
+
+impl<T: Debug> PropertyReport<T> {
+    // Panics with the report if the property failed.
+    pub fn assert_passed(&self) {
+        if !self.passed() {
+            panic!("{}", self);
+        }
+    }
+}
+
+impl<T: Debug> Display for PropertyReport<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PropertyReport::Passed {
+                samples,
+                exhausted: true,
+            } => write!(f, "passed for all {} values", samples),
+            PropertyReport::Passed { samples, .. } => write!(f, "passed for {} samples", samples),
+            PropertyReport::Failed(ref c) => {
+                write!(f, "failed at index {}: {:?}", c.index, c.value)?;
+                if let Some(ref shrunk) = c.shrunk {
+                    write!(f, "\nshrunk to: {:?}", shrunk)?;
+                }
+                if let Some(ref generator) = c.generator {
+                    write!(f, "\ngenerator: {}", generator)?;
+                }
+                if let Some(ref seed) = c.seed {
+                    write!(f, "\nseed: {}", seed)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Checks a property against up to samples values, stopping at the first failure.
+pub struct PropertyCheck<'a, T: 'a> {
+    samples: usize,
+    shrinker: Option<&'a dyn Shrinker<Item = T>>,
+}
+
+impl<'a, T: Clone> PropertyCheck<'a, T> {
+    pub fn new(samples: usize) -> PropertyCheck<'a, T> {
+        PropertyCheck {
+            samples,
+            shrinker: None,
+        }
+    }
+
+    pub fn with_shrinker(mut self, shrinker: &'a dyn Shrinker<Item = T>) -> PropertyCheck<'a, T> {
+        self.shrinker = Some(shrinker);
+        self
+    }
+
+    // xs may be random or exhaustive, finite or infinite.
+    pub fn run<I: Iterator<Item = T>, P: Fn(&T) -> bool>(
+        &self,
+        xs: I,
+        property: P,
+    ) -> PropertyReport<T> {
+        let mut xs = xs;
+        let mut samples = 0;
+        for (index, x) in (&mut xs).take(self.samples).enumerate() {
+            if !property(&x) {
+                let shrunk = self
+                    .shrinker
+                    .map(|shrinker| minimize(shrinker, x.clone(), |y| !property(y)));
+                return PropertyReport::Failed(Box::new(Counterexample {
+                    value: x,
+                    index,
+                    shrunk,
+                    seed: None,
+                    generator: None,
+                }));
+            }
+            samples += 1;
+        }
+        // If the budget ran out first, the values may have run out at the same time.
+        PropertyReport::Passed {
+            samples,
+            exhausted: samples < self.samples || xs.next().is_none(),
+        }
+    }
+
+    // Like run, but the report also records the seed and a description of the generator.
+    pub fn run_generator<I: Iterator<Item = T>, P: Fn(&T) -> bool>(
+        &self,
+        seed: &Seed,
+        xs_gen: &dyn Generator<Iter = I>,
+        property: P,
+    ) -> PropertyReport<T> {
+        let mut report = self.run(xs_gen.generate(seed), property);
+        if let PropertyReport::Failed(ref mut c) = report {
+            c.seed = Some(seed.clone());
+            c.generator = Some(xs_gen.description());
+        }
+        report
+    }
+}
+
+// Shrinks the value returned by generate(seed) by shrinking the raw draws behind it, rather than
+// the value itself, so it works with any combination of seed-based generators, including closures
+// and dependent pairs. generate must create its generators inside the call, from the seed it is
+// given; the seed's own backend is replaced by a recording ISAAC backend. Returns None if the
+// value generated from fresh draws satisfies the property.
+pub fn shrink_by_draws<T, F: Fn(&Seed) -> T, P: Fn(&T) -> bool>(
+    seed: &Seed,
+    generate: F,
+    property: P,
+) -> Option<T> {
+    let backend = RecordingBackend::new();
+    let seed = seed.clone().with_backend(backend.clone());
+    let x = generate(&seed);
+    if property(&x) {
+        return None;
+    }
+    let draws = minimize(&draws_shrinker(), backend.draws(), |draws| {
+        backend.replay(draws.clone());
+        let x = generate(&seed);
+        !backend.overran() && !property(&x)
+    });
+    backend.replay(draws);
+    Some(generate(&seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::general::random;
+    use iterators::primitive_ints::{exhaustive_unsigned, random_range, range_increasing};
+    use iterators::shrinkers::primitive_int_shrinker;
+
+    #[test]
+    fn test_run_passed() {
+        let passed = |samples, exhausted| PropertyReport::<u8>::Passed { samples, exhausted };
+        let xs = || range_increasing(0u8, 9);
+        assert_eq!(PropertyCheck::new(5).run(xs(), |_| true), passed(5, false));
+        assert_eq!(PropertyCheck::new(10).run(xs(), |_| true), passed(10, true));
+        assert_eq!(PropertyCheck::new(20).run(xs(), |_| true), passed(10, true));
+        assert_eq!(
+            PropertyCheck::new(10).run(exhaustive_unsigned::<u8>(), |_| true),
+            passed(10, false)
+        );
+        assert_eq!(passed(10, true).to_string(), "passed for all 10 values");
+        assert_eq!(passed(10, false).to_string(), "passed for 10 samples");
+    }
+
+    #[test]
+    fn test_run_failed() {
+        let report = PropertyCheck::new(1000).run(exhaustive_unsigned::<u32>(), |&x| x < 100);
+        assert!(!report.passed());
+        let c = report.counterexample().unwrap();
+        assert_eq!((c.value, c.index, c.shrunk), (100, 100, None));
+
+        let shrinker = primitive_int_shrinker();
+        let seed = Seed::example();
+        let xs_gen = |seed: &Seed| random_range(seed, 0u32, 1_000_000);
+        let report = PropertyCheck::new(1000)
+            .with_shrinker(&shrinker)
+            .run_generator(&seed, &xs_gen, |&x| x < 1000);
+        let c = report.counterexample().unwrap();
+        assert_eq!(xs_gen(&seed).nth(c.index), Some(c.value));
+        assert_eq!(c.shrunk, Some(1000));
+        assert_eq!(c.seed, Some(seed));
+        assert_eq!(c.generator, Some("closure".to_string()));
+    }
+
+    #[test]
+    fn test_shrink_by_draws() {
+        let seed = Seed::example();
+        let generate = |seed: &Seed| random::<u32>(seed).take(3).collect::<Vec<u32>>();
+        assert_eq!(
+            shrink_by_draws(&seed, generate, |xs| xs.iter().all(|&x| x < 1000)),
+            Some(vec![0, 0, 1000])
+        );
+        assert_eq!(shrink_by_draws(&seed, generate, |_| true), None);
+    }
+}
@@ -0,0 +1,619 @@
+use std::num::Wrapping;
+use std::sync::{Arc, Mutex};
+
+use malachite_base::num::conversion::traits::{CheckedFrom, ExactFrom, WrappingFrom};
+use rand::{ChaChaRng, IsaacRng, Rng, SeedableRng, XorShiftRng};
+
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
+
+// The random number generator behind every seed-based generator in this crate. It must stay
+// object-safe so that generators can hold a `Box<dyn WheelsRng>` regardless of the backend.
+pub trait WheelsRng: Rng + Send {}
+
+impl WheelsRng for IsaacRng {}
+
+impl WheelsRng for ChaChaRng {}
+
+impl WheelsRng for XorShiftRng {}
+
+// Produces consecutive `u32`s, starting from the first word of the seed. Useful for debugging,
+// since every draw is predictable.
+pub struct CountingRng {
+    next: u32,
+}
+
+impl Rng for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+        let x = self.next;
+        self.next = self.next.wrapping_add(1);
+        x
+    }
+}
+
+impl WheelsRng for CountingRng {}
+
+// Creates a fresh RNG from a seed. A seed's backend is chosen with `Seed::with_backend` and is
+// inherited by the seeds derived from it; seeds without one use ISAAC. Generators call the backend
+// once for every RNG they own, so a backend must always return the same sequence for the same
+// seed.
+pub trait RngBackend: Send + Sync {
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng>;
+
+    // Whether the RNGs of this backend can be checkpointed. Restoring one replays its draws, so a
+    // backend whose RNGs share their draws with each other must return false.
+    fn resumable(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IsaacBackend;
+
+impl RngBackend for IsaacBackend {
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng> {
+        Box::new(Isaac::from_seed(seed.words()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChaChaBackend;
+
+impl RngBackend for ChaChaBackend {
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng> {
+        Box::new(ChaChaRng::from_seed(seed.words()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct XorShiftBackend;
+
+impl RngBackend for XorShiftBackend {
+    // XorShift only takes four words, and they can't all be zero, so the seed is folded down.
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng> {
+        let mut words = [0; 4];
+        for (i, &x) in seed.words().iter().enumerate() {
+            words[i & 3] ^= x;
+        }
+        if words == [0; 4] {
+            words[0] = 1;
+        }
+        Box::new(XorShiftRng::from_seed(words))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CountingBackend;
+
+impl RngBackend for CountingBackend {
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng> {
+        Box::new(CountingRng {
+            next: seed.words()[0],
+        })
+    }
+}
+
+// The draws made by every RNG that a RecordingBackend has created, in the order they were made,
+// and the draws to replay in their place, if any.
+#[derive(Debug, Default)]
+struct DrawLog {
+    replay: Option<Vec<u32>>,
+    recorded: Vec<u32>,
+    overran: bool,
+}
+
+// Returns the replayed draws in order. If they run out, draws come from the wrapped RNG again, so
+// that generators which reject some draws and retry still terminate, and the overrun is noted.
+pub struct RecordingRng {
+    rng: Box<dyn WheelsRng>,
+    log: Arc<Mutex<DrawLog>>,
+}
+
+impl Rng for RecordingRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut log = self.log.lock().unwrap();
+        let i = log.recorded.len();
+        let replayed = log.replay.as_ref().map(|replay| replay.get(i).cloned());
+        let x = match replayed {
+            Some(Some(x)) => x,
+            Some(None) => {
+                log.overran = true;
+                self.rng.next_u32()
+            }
+            None => self.rng.next_u32(),
+        };
+        log.recorded.push(x);
+        x
+    }
+}
+
+impl WheelsRng for RecordingRng {}
+
+// Records every raw draw made by the generators created from seeds with this backend. Clones
+// share the same log, so a clone can be passed to `Seed::with_backend` and the original used to
+// read the draws afterwards. Replaying a modified sequence of draws lets any generator, including closures
+// and dependent pairs, be shrunk by shrinking its draws.
+#[derive(Clone, Default)]
+pub struct RecordingBackend<B: RngBackend = IsaacBackend> {
+    backend: B,
+    log: Arc<Mutex<DrawLog>>,
+}
+
+impl RecordingBackend<IsaacBackend> {
+    pub fn new() -> RecordingBackend<IsaacBackend> {
+        RecordingBackend::default()
+    }
+}
+
+impl<B: RngBackend> RecordingBackend<B> {
+    pub fn wrapping(backend: B) -> RecordingBackend<B> {
+        RecordingBackend {
+            backend,
+            log: Arc::new(Mutex::new(DrawLog::default())),
+        }
+    }
+
+    // The draws made since the backend was created or replay was last called.
+    pub fn draws(&self) -> Vec<u32> {
+        self.log.lock().unwrap().recorded.clone()
+    }
+
+    // Clears the recorded draws and makes the next draws return these instead.
+    pub fn replay(&self, draws: Vec<u32>) {
+        let mut log = self.log.lock().unwrap();
+        log.replay = Some(draws);
+        log.recorded.clear();
+        log.overran = false;
+    }
+
+    // Whether more draws were made than replay supplied. The values generated since then don't
+    // correspond to the replayed draws alone.
+    pub fn overran(&self) -> bool {
+        self.log.lock().unwrap().overran
+    }
+}
+
+impl<B: RngBackend> RngBackend for RecordingBackend<B> {
+    fn rng(&self, seed: &Seed) -> Box<dyn WheelsRng> {
+        Box::new(RecordingRng {
+            rng: self.backend.rng(seed),
+            log: Arc::clone(&self.log),
+        })
+    }
+
+    fn resumable(&self) -> bool {
+        self.backend.resumable()
+    }
+}
+
+// Once the bytes run out, each RNG draws 0, 1, ..., EXHAUSTED_CYCLE - 1 and then starts over, so
+// that generators produce small values however many draws were made, and any that retry rejected
+// draws still terminate.
+const EXHAUSTED_CYCLE: u32 = 1 << 8;
+
+// Draws 4 bytes at a time, little-endian, padding a final partial word with zeros.
+struct ByteSource {
+    bytes: Vec<u8>,
+    i: usize,
+}
+
+impl ByteSource {
+    fn next_word(&mut self) -> Option<u32> {
+        if self.i == self.bytes.len() {
+            return None;
+        }
+        let end = (self.i + 4).min(self.bytes.len());
+        let mut word = [0; 4];
+        word[..end - self.i].copy_from_slice(&self.bytes[self.i..end]);
+        self.i = end;
+        Some(u32::from_le_bytes(word))
+    }
+}
+
+pub struct BytesRng {
+    source: Arc<Mutex<ByteSource>>,
+    next_exhausted: u32,
+}
+
+impl Rng for BytesRng {
+    fn next_u32(&mut self) -> u32 {
+        match self.source.lock().unwrap().next_word() {
+            Some(x) => x,
+            None => {
+                let x = self.next_exhausted;
+                self.next_exhausted = (x + 1) % EXHAUSTED_CYCLE;
+                x
+            }
+        }
+    }
+}
+
+impl WheelsRng for BytesRng {}
+
+// Drives every generator created from seeds with this backend from one byte buffer, such as a
+// fuzzer's input, instead of from their seeds. The generators share the bytes in the order they
+// make draws, so they can't be checkpointed: restoring one would consume bytes meant for the
+// others.
+#[derive(Clone)]
+pub struct BytesBackend {
+    source: Arc<Mutex<ByteSource>>,
+}
+
+impl BytesBackend {
+    pub fn new(bytes: &[u8]) -> BytesBackend {
+        BytesBackend {
+            source: Arc::new(Mutex::new(ByteSource {
+                bytes: bytes.to_vec(),
+                i: 0,
+            })),
+        }
+    }
+}
+
+impl RngBackend for BytesBackend {
+    fn rng(&self, _seed: &Seed) -> Box<dyn WheelsRng> {
+        Box::new(BytesRng {
+            source: Arc::clone(&self.source),
+            next_exhausted: 0,
+        })
+    }
+
+    fn resumable(&self) -> bool {
+        false
+    }
+}
+
+// A seed whose generators all draw from bytes. See BytesBackend.
+pub fn bytes_seed(bytes: &[u8]) -> Seed {
+    Seed::example().with_backend(BytesBackend::new(bytes))
+}
+
+const ISAAC_WORDS: usize = 256;
+
+// A copy of rand's IsaacRng that produces exactly the same draws, but whose state can be read and
+// written, so that a checkpoint can hold the state itself rather than a count of draws.
+#[derive(Clone)]
+struct Isaac {
+    cnt: usize,
+    rsl: [Wrapping<u32>; ISAAC_WORDS],
+    mem: [Wrapping<u32>; ISAAC_WORDS],
+    a: Wrapping<u32>,
+    b: Wrapping<u32>,
+    c: Wrapping<u32>,
+}
+
+impl Isaac {
+    fn from_seed(seed: &[u32]) -> Isaac {
+        let mut rng = Isaac {
+            cnt: 0,
+            rsl: [Wrapping(0); ISAAC_WORDS],
+            mem: [Wrapping(0); ISAAC_WORDS],
+            a: Wrapping(0),
+            b: Wrapping(0),
+            c: Wrapping(0),
+        };
+        for (x, &y) in rng.rsl.iter_mut().zip(seed.iter()) {
+            *x = Wrapping(y);
+        }
+        rng.init();
+        rng
+    }
+
+    fn init(&mut self) {
+        let mut s = [Wrapping(0x9e37_79b9u32); 8];
+        fn mix(s: &mut [Wrapping<u32>; 8]) {
+            s[0] ^= s[1] << 11;
+            s[3] += s[0];
+            s[1] += s[2];
+            s[1] ^= s[2] >> 2;
+            s[4] += s[1];
+            s[2] += s[3];
+            s[2] ^= s[3] << 8;
+            s[5] += s[2];
+            s[3] += s[4];
+            s[3] ^= s[4] >> 16;
+            s[6] += s[3];
+            s[4] += s[5];
+            s[4] ^= s[5] << 10;
+            s[7] += s[4];
+            s[5] += s[6];
+            s[5] ^= s[6] >> 4;
+            s[0] += s[5];
+            s[6] += s[7];
+            s[6] ^= s[7] << 8;
+            s[1] += s[6];
+            s[7] += s[0];
+            s[7] ^= s[0] >> 9;
+            s[2] += s[7];
+            s[0] += s[1];
+        }
+        for _ in 0..4 {
+            mix(&mut s);
+        }
+        // The seed is mixed into mem, and then mem is mixed into itself.
+        for pass in 0..2 {
+            for i in (0..ISAAC_WORDS).step_by(8) {
+                let words = if pass == 0 {
+                    &self.rsl[i..i + 8]
+                } else {
+                    &self.mem[i..i + 8]
+                };
+                for (x, &y) in s.iter_mut().zip(words) {
+                    *x += y;
+                }
+                mix(&mut s);
+                self.mem[i..i + 8].copy_from_slice(&s);
+            }
+        }
+        self.isaac();
+    }
+
+    fn isaac(&mut self) {
+        const MIDPOINT: usize = ISAAC_WORDS / 2;
+        const SHIFTS: [(bool, usize); 4] = [(true, 13), (false, 6), (true, 2), (false, 16)];
+        self.c += Wrapping(1);
+        let mut a = self.a;
+        let mut b = self.b + self.c;
+        for &(mr_offset, m2_offset) in &[(0, MIDPOINT), (MIDPOINT, 0)] {
+            for i in 0..MIDPOINT {
+                let (left, shift) = SHIFTS[i & 3];
+                let mix = if left { a << shift } else { a >> shift };
+                let x = self.mem[i + mr_offset];
+                a = (a ^ mix) + self.mem[i + m2_offset];
+                let y = self.mem[usize::wrapping_from(x.0 >> 2) & (ISAAC_WORDS - 1)] + a + b;
+                self.mem[i + mr_offset] = y;
+                b = self.mem[usize::wrapping_from(y.0 >> 10) & (ISAAC_WORDS - 1)] + x;
+                self.rsl[i + mr_offset] = b;
+            }
+        }
+        self.a = a;
+        self.b = b;
+        self.cnt = ISAAC_WORDS;
+    }
+}
+
+impl Rng for Isaac {
+    fn next_u32(&mut self) -> u32 {
+        if self.cnt == 0 {
+            self.isaac();
+        }
+        self.cnt -= 1;
+        self.rsl[self.cnt].0
+    }
+}
+
+impl WheelsRng for Isaac {}
+
+// The state is cnt, a, b and c, followed by the words of rsl and then mem, two to a value.
+impl Resumable for Isaac {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(u64::exact_from(self.cnt));
+        state.push(u64::from(self.a.0));
+        state.push(u64::from(self.b.0));
+        state.push(u64::from(self.c.0));
+        for words in self.rsl.chunks(2).chain(self.mem.chunks(2)) {
+            state.push(u64::from(words[0].0) | u64::from(words[1].0) << 32);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        fn read_u32(state: &mut StateReader) -> Result<Wrapping<u32>, CheckpointError> {
+            let x = state.read()?;
+            u32::checked_from(x)
+                .map(Wrapping)
+                .ok_or(CheckpointError::InvalidValue(x))
+        }
+        let cnt = state.read()?;
+        self.cnt = match usize::checked_from(cnt) {
+            Some(cnt) if cnt <= ISAAC_WORDS => cnt,
+            _ => return Err(CheckpointError::InvalidValue(cnt)),
+        };
+        self.a = read_u32(state)?;
+        self.b = read_u32(state)?;
+        self.c = read_u32(state)?;
+        for words in self.rsl.chunks_mut(2).chain(self.mem.chunks_mut(2)) {
+            let x = state.read()?;
+            words[0] = Wrapping(u32::wrapping_from(x));
+            words[1] = Wrapping(u32::wrapping_from(x >> 32));
+        }
+        Ok(())
+    }
+}
+
+// ISAAC, the default, is held as a concrete type rather than a trait object, so that its draws are
+// not dispatched dynamically, and so that its state can be saved directly.
+enum SeededRngState {
+    Isaac(Box<Isaac>),
+    Backend(Box<dyn WheelsRng>),
+}
+
+// The RNG of a generator. It remembers its seed and how many `u32`s it has produced, so that the
+// position of an RNG from another backend can be restored too.
+pub struct SeededRng {
+    seed: Seed,
+    rng: SeededRngState,
+    draws: u64,
+}
+
+impl Rng for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        match self.rng {
+            SeededRngState::Isaac(ref mut rng) => rng.next_u32(),
+            SeededRngState::Backend(ref mut rng) => rng.next_u32(),
+        }
+    }
+}
+
+impl SeededRng {
+    fn resumable(&self) -> bool {
+        match self.seed.backend() {
+            Some(backend) => backend.resumable(),
+            None => true,
+        }
+    }
+}
+
+// The default ISAAC RNG saves its state words, so restoring it takes constant time. Other backends
+// can't expose their state through a `dyn WheelsRng`, so for them only the number of draws is
+// saved, and restoring replays the draws from the seed, taking time proportional to their number.
+// Saving panics, and restoring fails, for backends that aren't resumable.
+impl Resumable for SeededRng {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        match self.rng {
+            SeededRngState::Isaac(ref rng) => rng.save_state(state),
+            SeededRngState::Backend(_) => {
+                assert!(self.resumable(), "this RNG backend can't be checkpointed");
+                state.push(self.draws);
+            }
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        if let SeededRngState::Isaac(ref mut rng) = self.rng {
+            return rng.restore_state(state);
+        }
+        if !self.resumable() {
+            return Err(CheckpointError::Unsupported);
+        }
+        let draws = state.read()?;
+        if draws < self.draws {
+            *self = new_rng(&self.seed);
+        }
+        while self.draws < draws {
+            self.next_u32();
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn new_rng(seed: &Seed) -> SeededRng {
+    SeededRng {
+        seed: seed.clone(),
+        rng: match seed.backend() {
+            None => SeededRngState::Isaac(Box::new(Isaac::from_seed(seed.words()))),
+            Some(backend) => SeededRngState::Backend(backend.rng(seed)),
+        },
+        draws: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::checkpoints::Checkpoint;
+    use iterators::general::random;
+
+    #[test]
+    fn test_isaac_matches_rand() {
+        let seed = Seed::example();
+        let mut rng = Isaac::from_seed(seed.words());
+        let mut expected = IsaacRng::from_seed(seed.words());
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn test_isaac_checkpoint() {
+        let mut rng = new_rng(&Seed::example());
+        for _ in 0..300 {
+            rng.next_u32();
+        }
+        let checkpoint = rng.checkpoint();
+        // cnt, a, b and c, followed by 512 words, two to a value
+        assert_eq!(checkpoint.0.len(), 260);
+        let mut restored = new_rng(&Seed::example());
+        restored.resume(&checkpoint).unwrap();
+        for _ in 0..300 {
+            assert_eq!(restored.next_u32(), rng.next_u32());
+        }
+
+        let mut bad = checkpoint.clone();
+        bad.0[0] = 257;
+        assert_eq!(
+            restored.resume(&bad),
+            Err(CheckpointError::InvalidValue(257))
+        );
+        assert_eq!(
+            restored.resume(&Checkpoint(checkpoint.0[..259].to_vec())),
+            Err(CheckpointError::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_backend_checkpoints() {
+        assert_resumes(|| random::<u32>(&Seed::example()), 100);
+        assert_resumes(
+            || random::<u32>(&Seed::example().with_backend(ChaChaBackend)),
+            100,
+        );
+        assert_resumes(
+            || random::<u32>(&Seed::example().with_backend(XorShiftBackend)),
+            100,
+        );
+    }
+
+    #[test]
+    fn test_recording_backend() {
+        let backend = RecordingBackend::new();
+        let seed = Seed::example().with_backend(backend.clone());
+        let xs: Vec<u32> = random(&seed).take(5).collect();
+        assert_eq!(backend.draws(), xs);
+        let expected: Vec<u32> = random(&Seed::example()).take(5).collect();
+        assert_eq!(xs, expected);
+
+        backend.replay(vec![1, 2, 3]);
+        let xs: Vec<u32> = random(&seed).take(3).collect();
+        assert_eq!(xs, [1, 2, 3]);
+        assert!(!backend.overran());
+        assert_eq!(backend.draws(), [1, 2, 3]);
+
+        backend.replay(vec![1, 2, 3]);
+        let xs: Vec<u32> = random(&seed).take(5).collect();
+        assert_eq!(xs[..3], [1, 2, 3]);
+        assert!(backend.overran());
+    }
+
+    #[test]
+    fn test_bytes_seed() {
+        let seed = bytes_seed(&[1, 0, 0, 0, 0, 1, 0, 0, 5]);
+        let xs: Vec<u32> = random(&seed).take(5).collect();
+        assert_eq!(xs, [1, 256, 5, 0, 1]);
+        // Every generator draws from the same bytes.
+        let seed = bytes_seed(&[1, 0, 0, 0, 2, 0, 0, 0]);
+        let mut xs = random::<u32>(&seed);
+        let mut ys = random::<u32>(&seed);
+        assert_eq!(
+            (xs.next(), ys.next(), xs.next()),
+            (Some(1), Some(2), Some(0))
+        );
+    }
+
+    #[test]
+    fn test_bytes_seed_exhausted() {
+        let seed = bytes_seed(&[]);
+        let xs: Vec<u32> = random(&seed).take(258).collect();
+        assert_eq!(xs[..3], [0, 1, 2]);
+        assert_eq!(xs[255..], [255, 0, 1]);
+        // Each generator starts the pattern over, whatever the others drew.
+        let ys: Vec<u32> = random(&seed).take(3).collect();
+        assert_eq!(ys, [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "this RNG backend can't be checkpointed")]
+    fn test_bytes_seed_checkpoint_fail() {
+        random::<u32>(&bytes_seed(&[1, 2, 3])).checkpoint();
+    }
+
+    #[test]
+    fn test_bytes_seed_resume_fail() {
+        let mut xs = random::<u32>(&bytes_seed(&[1, 2, 3]));
+        assert_eq!(
+            xs.resume(&Checkpoint(vec![0])),
+            Err(CheckpointError::Unsupported)
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use malachite_base::rounding_modes::RoundingMode;
 
+use iterators::common::Seed;
 use iterators::general::{random_from_vector, RandomFromVector};
 
-pub fn random_rounding_modes(seed: &[u32]) -> RandomFromVector<RoundingMode> {
+pub fn random_rounding_modes(seed: &Seed) -> RandomFromVector<RoundingMode> {
     random_from_vector(
         seed,
         vec![
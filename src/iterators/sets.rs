@@ -0,0 +1,359 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::BitConvertible;
+use rand::Rng;
+
+use iterators::checkpoints::Resumable;
+use iterators::common::Seed;
+use iterators::general::CachedIterator;
+use iterators::generators::Generator;
+use iterators::integers_geometric::{u32s_geometric, U32sGeometric};
+use iterators::naturals::{exhaustive_naturals, ExhaustiveNaturals};
+use iterators::rngs::{new_rng, SeededRng};
+
+// How many values in a row may already be in a random set before it is returned with fewer values
+// than intended.
+const MAX_CONSECUTIVE_DUPLICATES: usize = 64;
+
+macro_rules! random_sets {
+    (
+        $struct_name: ident,
+        $fn_name: ident,
+        $set_type: ident,
+        [$($bound: tt)*]
+    ) => {
+        pub struct $struct_name<I: Iterator>
+        where
+            I::Item: $($bound)*,
+        {
+            lengths: U32sGeometric,
+            xs: I,
+        }
+
+        impl<I: Iterator> Iterator for $struct_name<I>
+        where
+            I::Item: $($bound)*,
+        {
+            type Item = $set_type<I::Item>;
+
+            fn next(&mut self) -> Option<$set_type<I::Item>> {
+                let len = usize::exact_from(self.lengths.next().unwrap());
+                let mut set = $set_type::new();
+                let mut duplicates = 0;
+                while set.len() < len && duplicates < MAX_CONSECUTIVE_DUPLICATES {
+                    if set.insert(self.xs.next().unwrap()) {
+                        duplicates = 0;
+                    } else {
+                        duplicates += 1;
+                    }
+                }
+                Some(set)
+            }
+        }
+
+        resumable!(
+            [I: Iterator],
+            $struct_name<I>,
+            [lengths, xs],
+            [I::Item: $($bound)*, I: Resumable,]
+        );
+
+        // The set lengths have a geometric distribution with mean scale. If xs generates fewer
+        // distinct values than the chosen length, as a generator of bools or u8s may, the set is
+        // returned once MAX_CONSECUTIVE_DUPLICATES values in a row fail to add to it; it then
+        // almost always contains every value xs can generate. So sets over small inputs are
+        // shorter, and full sets more common, than the length distribution alone suggests.
+        pub fn $fn_name<G: Generator + ?Sized>(
+            seed: &Seed,
+            scale: u32,
+            xs_gen: &G,
+        ) -> $struct_name<G::Iter>
+        where
+            <G::Iter as Iterator>::Item: $($bound)*,
+        {
+            $struct_name {
+                lengths: u32s_geometric(&seed.derive("lengths"), scale),
+                xs: xs_gen.generate(&seed.derive("xs")),
+            }
+        }
+    };
+}
+
+random_sets!(RandomBTreeSets, random_b_tree_sets, BTreeSet, [Ord]);
+random_sets!(RandomHashSets, random_hash_sets, HashSet, [Eq + Hash]);
+
+pub struct RandomSubsets<T: Clone> {
+    xs: Vec<T>,
+    lengths: U32sGeometric,
+    rng: SeededRng,
+}
+
+impl<T: Clone> Iterator for RandomSubsets<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let n = self.xs.len();
+        let len = loop {
+            let len = usize::exact_from(self.lengths.next().unwrap());
+            if len <= n {
+                break len;
+            }
+        };
+        // Floyd's algorithm for choosing len distinct indices uniformly.
+        let mut indices = BTreeSet::new();
+        for j in n - len..n {
+            let k = self.rng.gen_range(0, j + 1);
+            if !indices.insert(k) {
+                indices.insert(j);
+            }
+        }
+        Some(indices.into_iter().map(|i| self.xs[i].clone()).collect())
+    }
+}
+
+resumable!([T: Clone], RandomSubsets<T>, [lengths, rng], []);
+
+// Generates subsets of the finite iterator xs, each of which preserves the order of xs.
+pub fn random_subsets<I: Iterator>(seed: &Seed, scale: u32, xs: I) -> RandomSubsets<I::Item>
+where
+    I::Item: Clone,
+{
+    RandomSubsets {
+        xs: xs.collect(),
+        lengths: u32s_geometric(&seed.derive("lengths"), scale),
+        rng: new_rng(&seed.derive("xs")),
+    }
+}
+
+pub struct ExhaustiveSubsets<I: Iterator>
+where
+    I::Item: Clone,
+{
+    xs: CachedIterator<I>,
+    indices: ExhaustiveNaturals,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for ExhaustiveSubsets<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
+        }
+        // The ith subset contains the jth element of xs if and only if the jth bit of i is set.
+        let mut subset = Vec::new();
+        let i = self.indices.next().unwrap();
+        for (j, bit) in i.to_bits_asc().into_iter().enumerate() {
+            if bit {
+                match self.xs.get(j) {
+                    Some(x) => subset.push(x),
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                }
+            }
+        }
+        Some(subset)
+    }
+}
+
+resumable!(
+    [I: Iterator],
+    ExhaustiveSubsets<I>,
+    [xs, indices, done],
+    [I::Item: Clone,]
+);
+
+// Generates subsets of xs, each of which preserves the order of xs. xs may be finite or infinite.
+pub fn exhaustive_subsets<I: Iterator>(xs: I) -> ExhaustiveSubsets<I>
+where
+    I::Item: Clone,
+{
+    ExhaustiveSubsets {
+        xs: CachedIterator::new(xs),
+        indices: exhaustive_naturals(),
+        done: false,
+    }
+}
+
+pub struct ExhaustiveBTreeSets<I: Iterator>(ExhaustiveSubsets<I>)
+where
+    I::Item: Clone + Ord;
+
+impl<I: Iterator> Iterator for ExhaustiveBTreeSets<I>
+where
+    I::Item: Clone + Ord,
+{
+    type Item = BTreeSet<I::Item>;
+
+    fn next(&mut self) -> Option<BTreeSet<I::Item>> {
+        self.0.next().map(|xs| xs.into_iter().collect())
+    }
+}
+
+resumable!(
+    [I: Iterator],
+    ExhaustiveBTreeSets<I>,
+    [0],
+    [I::Item: Clone + Ord,]
+);
+
+// The values generated by xs must be distinct.
+pub fn exhaustive_b_tree_sets<I: Iterator>(xs: I) -> ExhaustiveBTreeSets<I>
+where
+    I::Item: Clone + Ord,
+{
+    ExhaustiveBTreeSets(exhaustive_subsets(xs))
+}
+
+pub struct ExhaustiveHashSets<I: Iterator>(ExhaustiveSubsets<I>)
+where
+    I::Item: Clone + Eq + Hash;
+
+impl<I: Iterator> Iterator for ExhaustiveHashSets<I>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    type Item = HashSet<I::Item>;
+
+    fn next(&mut self) -> Option<HashSet<I::Item>> {
+        self.0.next().map(|xs| xs.into_iter().collect())
+    }
+}
+
+resumable!(
+    [I: Iterator],
+    ExhaustiveHashSets<I>,
+    [0],
+    [I::Item: Clone + Eq + Hash,]
+);
+
+// The values generated by xs must be distinct.
+pub fn exhaustive_hash_sets<I: Iterator>(xs: I) -> ExhaustiveHashSets<I>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    ExhaustiveHashSets(exhaustive_subsets(xs))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::general::random;
+    use iterators::primitive_ints::{exhaustive_unsigned, range_increasing};
+
+    fn assert_distinct<T: Debug + Eq + Hash>(xss: &[T]) {
+        let set: HashSet<&T> = xss.iter().collect();
+        assert_eq!(set.len(), xss.len(), "{:?}", xss);
+    }
+
+    fn assert_increasing(xs: &[u8]) {
+        assert!(xs.windows(2).all(|w| w[0] < w[1]), "{:?}", xs);
+    }
+
+    #[test]
+    fn test_random_sets() {
+        let seed = Seed::example();
+        let xs_gen = |seed: &Seed| random::<u8>(seed);
+        let b_tree_sets: Vec<BTreeSet<u8>> =
+            random_b_tree_sets(&seed, 4, &xs_gen).take(1000).collect();
+        let first: Vec<Vec<u8>> = b_tree_sets[..3]
+            .iter()
+            .map(|set| set.iter().cloned().collect())
+            .collect();
+        assert_eq!(
+            first,
+            [
+                vec![55, 82, 126, 146, 176, 227, 237],
+                vec![
+                    2, 21, 50, 54, 61, 72, 76, 82, 93, 117, 122, 156, 157, 164, 183, 200, 203, 214,
+                    226,
+                ],
+                vec![43, 68, 139, 200],
+            ]
+        );
+        // The hash sets are made from the same values.
+        for (b_tree_set, hash_set) in b_tree_sets.iter().zip(random_hash_sets(&seed, 4, &xs_gen)) {
+            assert_eq!(hash_set.into_iter().collect::<BTreeSet<_>>(), *b_tree_set);
+        }
+    }
+
+    #[test]
+    fn test_random_subsets() {
+        let subsets: Vec<Vec<u8>> = random_subsets(&Seed::example(), 4, range_increasing(0, 9))
+            .take(1000)
+            .collect();
+        assert_eq!(
+            subsets[..5],
+            [
+                vec![1, 3, 4, 5, 6, 8, 9],
+                vec![5, 6, 7, 8],
+                vec![2, 4, 7, 9],
+                vec![6],
+                vec![2, 3, 6, 9],
+            ]
+        );
+        for subset in subsets {
+            assert_increasing(&subset);
+            assert!(subset.iter().all(|&x| x <= 9));
+        }
+    }
+
+    #[test]
+    fn test_exhaustive_subsets() {
+        let subsets: Vec<Vec<u8>> = exhaustive_subsets(range_increasing(1, 4)).collect();
+        assert_eq!(
+            subsets[..6],
+            [vec![], vec![1], vec![2], vec![1, 2], vec![3], vec![1, 3]]
+        );
+        assert_eq!(subsets.len(), 16);
+        assert_distinct(&subsets);
+        for subset in &subsets {
+            assert_increasing(subset);
+        }
+        // With an infinite input, every subset of the first 10 values comes first.
+        let infinite_subsets: Vec<Vec<u8>> = exhaustive_subsets(exhaustive_unsigned())
+            .take(1 << 10)
+            .collect();
+        assert_distinct(&infinite_subsets);
+        assert!(infinite_subsets
+            .iter()
+            .all(|subset| subset.iter().all(|&x| x < 10)));
+
+        let sets: Vec<BTreeSet<u8>> = exhaustive_b_tree_sets(range_increasing(1, 4)).collect();
+        assert_eq!(sets.len(), 16);
+        assert_distinct(&sets);
+        let sets: Vec<Vec<u8>> = exhaustive_hash_sets(range_increasing(1, 4))
+            .map(|set| {
+                set.into_iter()
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .collect();
+        assert_eq!(sets, subsets[..16]);
+    }
+
+    #[test]
+    fn test_sets_checkpoints() {
+        let xs_gen = |seed: &Seed| random::<u8>(seed);
+        assert_resumes(|| random_b_tree_sets(&Seed::example(), 4, &xs_gen), 20);
+        assert_resumes(
+            || random_subsets(&Seed::example(), 4, range_increasing(0u8, 9)),
+            20,
+        );
+        assert_resumes(|| exhaustive_subsets(range_increasing(0u8, 5)), 30);
+        assert_resumes(|| exhaustive_b_tree_sets(exhaustive_unsigned::<u32>()), 100);
+        assert_resumes(|| exhaustive_hash_sets(range_increasing(0u8, 5)), 30);
+    }
+}
@@ -0,0 +1,405 @@
+use std::marker::PhantomData;
+
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Something that proposes simpler replacements for a value that makes a property fail. Every
+// candidate must be strictly simpler than the value it came from, by some measure that can't
+// decrease forever, so that repeatedly shrinking terminates.
+pub trait Shrinker {
+    type Item;
+
+    // Returns the candidates for x, simplest first.
+    fn shrink(&self, x: &Self::Item) -> Vec<Self::Item>;
+}
+
+// Repeatedly replaces x with the first candidate that still fails, until none of them do.
+pub fn minimize<T, F: Fn(&T) -> bool>(shrinker: &dyn Shrinker<Item = T>, mut x: T, fails: F) -> T {
+    while let Some(y) = shrinker.shrink(&x).into_iter().find(|y| fails(y)) {
+        x = y;
+    }
+    x
+}
+
+// Returns the boundary (0, T::MIN or T::MAX) closest to x, and the distance to it. Ties go to 0.
+fn nearest_boundary<T: PrimitiveInt>(x: T) -> (T, T) {
+    if x >= T::ZERO {
+        let to_max = T::MAX - x;
+        if x <= to_max {
+            (T::ZERO, x)
+        } else {
+            (T::MAX, to_max)
+        }
+    } else {
+        // x is not T::MIN, so neither subtraction overflows.
+        let to_zero = T::ZERO - x;
+        let to_min = x - T::MIN;
+        if to_zero <= to_min {
+            (T::ZERO, to_zero)
+        } else {
+            (T::MIN, to_min)
+        }
+    }
+}
+
+pub struct PrimitiveIntShrinker<T: PrimitiveInt> {
+    boo: PhantomData<*const T>,
+}
+
+impl<T: PrimitiveInt> Shrinker for PrimitiveIntShrinker<T> {
+    type Item = T;
+
+    // A value shrinks to 0, then to the nearest of 0, T::MIN and T::MAX, and then toward that
+    // boundary, halving the step each time. T::MIN and T::MAX shrink to 0.
+    fn shrink(&self, &x: &T) -> Vec<T> {
+        if x == T::ZERO {
+            return Vec::new();
+        }
+        let mut candidates = vec![T::ZERO];
+        if x == T::MIN || x == T::MAX {
+            return candidates;
+        }
+        let (b, distance) = nearest_boundary(x);
+        if b != T::ZERO {
+            candidates.push(b);
+        }
+        let two = T::ONE + T::ONE;
+        let mut step = distance / two;
+        while step != T::ZERO {
+            candidates.push(if x > b { x - step } else { x + step });
+            step /= two;
+        }
+        candidates
+    }
+}
+
+pub fn primitive_int_shrinker<T: PrimitiveInt>() -> PrimitiveIntShrinker<T> {
+    PrimitiveIntShrinker { boo: PhantomData }
+}
+
+pub struct NaturalShrinker;
+
+impl Shrinker for NaturalShrinker {
+    type Item = Natural;
+
+    // Every candidate is less than x: first 0, then x with one of its limbs removed, then x with
+    // one of its bits cleared, starting from the most significant.
+    fn shrink(&self, x: &Natural) -> Vec<Natural> {
+        let mut candidates = Vec::new();
+        if *x == Natural::ZERO {
+            return candidates;
+        }
+        candidates.push(Natural::ZERO);
+        let limbs = x.to_limbs_asc();
+        if limbs.len() > 1 {
+            for i in (0..limbs.len()).rev() {
+                let mut limbs = limbs.clone();
+                limbs.remove(i);
+                candidates.push(Natural::from_owned_limbs_asc(limbs));
+            }
+        }
+        for i in (0..x.significant_bits()).rev() {
+            if x.get_bit(i) {
+                let mut y = x.clone();
+                y.clear_bit(i);
+                if y != Natural::ZERO {
+                    candidates.push(y);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+pub fn natural_shrinker() -> NaturalShrinker {
+    NaturalShrinker
+}
+
+pub struct IntegerShrinker;
+
+impl Shrinker for IntegerShrinker {
+    type Item = Integer;
+
+    // A negative x first shrinks to -x, and then its absolute value is shrunk as a Natural,
+    // keeping the sign.
+    fn shrink(&self, x: &Integer) -> Vec<Integer> {
+        let negative = *x < Integer::ZERO;
+        let mut candidates = Vec::new();
+        if negative {
+            candidates.push(-x);
+        }
+        for n in NaturalShrinker.shrink(&x.clone().unsigned_abs()) {
+            let n = Integer::from(n);
+            candidates.push(if negative { -n } else { n });
+        }
+        candidates
+    }
+}
+
+pub fn integer_shrinker() -> IntegerShrinker {
+    IntegerShrinker
+}
+
+pub struct VecsShrinker<'a, T: 'a> {
+    xs_shrinker: &'a dyn Shrinker<Item = T>,
+}
+
+impl<'a, T: Clone> Shrinker for VecsShrinker<'a, T> {
+    type Item = Vec<T>;
+
+    // First the empty Vec, then xs with chunks of decreasing length removed, then xs with one of
+    // its elements shrunk.
+    fn shrink(&self, xs: &Vec<T>) -> Vec<Vec<T>> {
+        let mut candidates = Vec::new();
+        if xs.is_empty() {
+            return candidates;
+        }
+        candidates.push(Vec::new());
+        let len = xs.len();
+        let mut chunk_len = len / 2;
+        while chunk_len != 0 {
+            for start in (0..len).step_by(chunk_len) {
+                let end = (start + chunk_len).min(len);
+                let mut ys = xs[..start].to_vec();
+                ys.extend_from_slice(&xs[end..]);
+                candidates.push(ys);
+            }
+            chunk_len /= 2;
+        }
+        for (i, x) in xs.iter().enumerate() {
+            for y in self.xs_shrinker.shrink(x) {
+                let mut ys = xs.clone();
+                ys[i] = y;
+                candidates.push(ys);
+            }
+        }
+        candidates
+    }
+}
+
+pub fn vecs_shrinker<'a, T>(xs_shrinker: &'a dyn Shrinker<Item = T>) -> VecsShrinker<'a, T> {
+    VecsShrinker { xs_shrinker }
+}
+
+// Shrinks toward 0 only. The raw draws of an RNG have no meaningful boundaries.
+struct DrawShrinker;
+
+impl Shrinker for DrawShrinker {
+    type Item = u32;
+
+    fn shrink(&self, &x: &u32) -> Vec<u32> {
+        let mut candidates = Vec::new();
+        let mut step = x;
+        while step != 0 {
+            candidates.push(x - step);
+            step >>= 1;
+        }
+        candidates
+    }
+}
+
+// Shrinks the raw draws recorded by a RecordingBackend. Fewer and smaller draws generally make
+// the generators in this crate produce shorter and smaller values.
+pub struct DrawsShrinker;
+
+impl Shrinker for DrawsShrinker {
+    type Item = Vec<u32>;
+
+    fn shrink(&self, draws: &Vec<u32>) -> Vec<Vec<u32>> {
+        vecs_shrinker(&DrawShrinker).shrink(draws)
+    }
+}
+
+pub fn draws_shrinker() -> DrawsShrinker {
+    DrawsShrinker
+}
+
+macro_rules! tuple_shrinker {
+    (
+        $struct_name: ident, $fn_name: ident,
+        $(
+            [ $t: ident, $shrinker: ident, $i: tt ]
+        ),*
+    ) => {
+        pub struct $struct_name<'a, $($t: 'a),*> {
+            $(
+                $shrinker: &'a dyn Shrinker<Item = $t>,
+            )*
+        }
+
+        // Shrinks one element at a time, leaving the others unchanged.
+        impl<'a, $($t: Clone),*> Shrinker for $struct_name<'a, $($t),*> {
+            type Item = ($($t),*);
+
+            fn shrink(&self, x: &($($t),*)) -> Vec<($($t),*)> {
+                let mut candidates = Vec::new();
+                $(
+                    for y in self.$shrinker.shrink(&x.$i) {
+                        let mut candidate = x.clone();
+                        candidate.$i = y;
+                        candidates.push(candidate);
+                    }
+                )*
+                candidates
+            }
+        }
+
+        #[allow(unknown_lints, too_many_arguments)]
+        pub fn $fn_name<'a, $($t),*>(
+            $($shrinker: &'a dyn Shrinker<Item = $t>),*
+        ) -> $struct_name<'a, $($t),*> {
+            $struct_name {
+                $(
+                    $shrinker,
+                )*
+            }
+        }
+    }
+}
+
+tuple_shrinker!(
+    PairsShrinker,
+    pairs_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1]
+);
+tuple_shrinker!(
+    TriplesShrinker,
+    triples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2]
+);
+tuple_shrinker!(
+    QuadruplesShrinker,
+    quadruples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2],
+    [L, ws_shrinker, 3]
+);
+tuple_shrinker!(
+    QuintuplesShrinker,
+    quintuples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2],
+    [L, ws_shrinker, 3],
+    [M, vs_shrinker, 4]
+);
+tuple_shrinker!(
+    SextuplesShrinker,
+    sextuples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2],
+    [L, ws_shrinker, 3],
+    [M, vs_shrinker, 4],
+    [N, us_shrinker, 5]
+);
+tuple_shrinker!(
+    SeptuplesShrinker,
+    septuples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2],
+    [L, ws_shrinker, 3],
+    [M, vs_shrinker, 4],
+    [N, us_shrinker, 5],
+    [O, ts_shrinker, 6]
+);
+tuple_shrinker!(
+    OctuplesShrinker,
+    octuples_shrinker,
+    [I, xs_shrinker, 0],
+    [J, ys_shrinker, 1],
+    [K, zs_shrinker, 2],
+    [L, ws_shrinker, 3],
+    [M, vs_shrinker, 4],
+    [N, us_shrinker, 5],
+    [O, ts_shrinker, 6],
+    [P, ss_shrinker, 7]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_int_shrinker() {
+        let shrinker = primitive_int_shrinker::<i8>();
+        assert_eq!(shrinker.shrink(&100), vec![0, 127, 113, 106, 103, 101]);
+        assert_eq!(shrinker.shrink(&-3), vec![0, -2]);
+        assert_eq!(shrinker.shrink(&i8::MIN), vec![0]);
+        assert!(shrinker.shrink(&0).is_empty());
+        assert_eq!(
+            minimize(&primitive_int_shrinker::<u32>(), 123_456, |&x| x >= 1000),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_natural_and_integer_shrinkers() {
+        let n = |x: u32| Natural::from(x);
+        assert_eq!(natural_shrinker().shrink(&n(5)), vec![n(0), n(1), n(4)]);
+        assert!(natural_shrinker().shrink(&n(0)).is_empty());
+        let mut x = Natural::ZERO;
+        for &i in &[0, 3, 70, 80, 100] {
+            x.set_bit(i);
+        }
+        let mut expected = Natural::ZERO;
+        expected.set_bit(70);
+        assert_eq!(
+            minimize(&natural_shrinker(), x, |y| y.get_bit(70)),
+            expected
+        );
+
+        let i = |x: i32| Integer::from(x);
+        assert_eq!(
+            integer_shrinker().shrink(&i(-5)),
+            vec![i(5), i(0), i(-1), i(-4)]
+        );
+        assert_eq!(integer_shrinker().shrink(&i(5)), vec![i(0), i(1), i(4)]);
+    }
+
+    #[test]
+    fn test_vecs_and_tuple_shrinkers() {
+        let u8_shrinker = primitive_int_shrinker::<u8>();
+        assert_eq!(
+            vecs_shrinker(&u8_shrinker).shrink(&vec![1, 2, 3]),
+            vec![
+                vec![],
+                vec![2, 3],
+                vec![1, 3],
+                vec![1, 2],
+                vec![0, 2, 3],
+                vec![1, 0, 3],
+                vec![1, 1, 3],
+                vec![1, 2, 0],
+                vec![1, 2, 2],
+            ]
+        );
+        assert_eq!(
+            minimize(&vecs_shrinker(&u8_shrinker), vec![5, 200, 7, 30], |xs| {
+                xs.iter().any(|&x| x >= 20)
+            }),
+            vec![20]
+        );
+        assert_eq!(
+            minimize(
+                &pairs_shrinker(&u8_shrinker, &u8_shrinker),
+                (100, 50),
+                |&(x, y)| x >= 10 && y >= 20
+            ),
+            (10, 20)
+        );
+        assert_eq!(
+            draws_shrinker().shrink(&vec![4]),
+            vec![vec![], vec![0], vec![2], vec![3]]
+        );
+    }
+}
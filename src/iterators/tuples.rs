@@ -1,7 +1,12 @@
 use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
 
-use iterators::common::scramble;
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::Seed;
+use iterators::general::CachedIterator;
+use iterators::generators::Generator;
 
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct LogPairIndices(u64);
@@ -11,6 +16,26 @@ impl LogPairIndices {
         LogPairIndices(1)
     }
 
+    // The indices of the nth pair, counting from 0. n must be less than 2^64 - 1.
+    pub(crate) fn from_index(n: u64) -> LogPairIndices {
+        LogPairIndices(n + 1)
+    }
+
+    pub(crate) fn to_index(&self) -> u64 {
+        self.0 - 1
+    }
+
+    // The inverse of indices. Returns None if the pair's index doesn't fit in a u64.
+    pub(crate) fn from_indices(x: usize, y: usize) -> Option<LogPairIndices> {
+        let x = u64::exact_from(x).checked_mul(2)?.checked_add(1)?;
+        let y = u32::exact_from(y);
+        if y < 64 && x.leading_zeros() >= y {
+            Some(LogPairIndices(x << y))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn increment(&mut self) {
         self.0 += 1;
     }
@@ -21,6 +46,22 @@ impl LogPairIndices {
     }
 }
 
+impl Resumable for LogPairIndices {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.push(self.0);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        match state.read()? {
+            0 => Err(CheckpointError::InvalidValue(0)),
+            i => {
+                self.0 = i;
+                Ok(())
+            }
+        }
+    }
+}
+
 impl Default for LogPairIndices {
     fn default() -> LogPairIndices {
         LogPairIndices(1)
@@ -32,9 +73,39 @@ pub(crate) struct ZOrderTupleIndices(pub Vec<u64>);
 
 impl ZOrderTupleIndices {
     pub(crate) fn new(size: u64) -> ZOrderTupleIndices {
-        let mut v = Vec::new();
-        v.resize(usize::exact_from(size), 0);
-        ZOrderTupleIndices(v)
+        ZOrderTupleIndices(vec![0; usize::exact_from(size)])
+    }
+
+    // Bit k of n becomes bit k / size of the index in position size - 1 - k % size, which is
+    // the order in which increment counts.
+    pub(crate) fn from_index(size: u64, n: u64) -> ZOrderTupleIndices {
+        let mut indices = ZOrderTupleIndices::new(size);
+        let size = usize::exact_from(size);
+        for k in 0..64 {
+            if n.get_bit(k) {
+                let k = usize::exact_from(k);
+                indices.0[size - 1 - k % size].set_bit(u64::exact_from(k / size));
+            }
+        }
+        indices
+    }
+
+    // The inverse of from_index. Returns None if the index doesn't fit in a u64.
+    pub(crate) fn to_index(&self) -> Option<u64> {
+        let size = u64::exact_from(self.0.len());
+        let mut n = 0u64;
+        for (i, &x) in self.0.iter().rev().enumerate() {
+            for j in 0..x.significant_bits() {
+                if x.get_bit(j) {
+                    let k = j.checked_mul(size)?.checked_add(u64::exact_from(i))?;
+                    if k >= 64 {
+                        return None;
+                    }
+                    n.set_bit(k);
+                }
+            }
+        }
+        Some(n)
     }
 
     pub(crate) fn increment(&mut self) {
@@ -52,6 +123,434 @@ impl ZOrderTupleIndices {
     }
 }
 
+impl Resumable for ZOrderTupleIndices {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        state.extend_from_slice(&self.0);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        for i in &mut self.0 {
+            *i = state.read()?;
+        }
+        Ok(())
+    }
+}
+
+// The functions below count the points of a Z-order whose coordinates are less than the given
+// bounds, where None means that a coordinate is unbounded.
+
+// The number of points whose Z-order indices agree with prefix above bit k, have a 0 at bit k, and
+// are arbitrary below it.
+fn z_points_with_prefix(bounds: &[Option<u64>], prefix: u64, k: u64) -> u64 {
+    let size = u64::exact_from(bounds.len());
+    let base = ZOrderTupleIndices::from_index(size, prefix);
+    let mut count = 1;
+    for (i, (&bound, &x)) in bounds.iter().zip(base.0.iter()).enumerate() {
+        let free = u64::power_of_2((k + u64::exact_from(i)) / size);
+        count *= match bound {
+            Some(bound) => bound.saturating_sub(x).min(free),
+            None => free,
+        };
+    }
+    count
+}
+
+fn z_point_is_valid(bounds: &[Option<u64>], indices: &[u64]) -> bool {
+    bounds
+        .iter()
+        .zip(indices.iter())
+        .all(|(&bound, &i)| i < bound.unwrap_or(u64::MAX))
+}
+
+// The number of points whose Z-order indices are less than z.
+pub(crate) fn z_points_below(bounds: &[Option<u64>], z: u64) -> u64 {
+    if bounds.is_empty() {
+        // The only point is the empty tuple.
+        return z.min(1);
+    }
+    let mut count = 0;
+    for k in 0..u64::WIDTH {
+        if z.get_bit(k) {
+            count += z_points_with_prefix(bounds, z & !u64::low_mask(k + 1), k);
+        }
+    }
+    count
+}
+
+// The inverse of z_points_below: the Z-order index of the point with j points before it, if it
+// is less than 2^bits.
+fn z_point(bounds: &[Option<u64>], mut j: u64, bits: u64) -> Option<u64> {
+    if bounds.is_empty() {
+        return if j == 0 { Some(0) } else { None };
+    }
+    let mut z = 0u64;
+    for k in (0..bits).rev() {
+        let count = z_points_with_prefix(bounds, z, k);
+        if j >= count {
+            j -= count;
+            z.set_bit(k);
+        }
+    }
+    let indices = ZOrderTupleIndices::from_index(u64::exact_from(bounds.len()), z);
+    if j == 0 && z_point_is_valid(bounds, &indices.0) {
+        Some(z)
+    } else {
+        None
+    }
+}
+
+// The smallest k such that x <= 2^k.
+fn ceiling_log_2(x: usize) -> u64 {
+    if x <= 1 {
+        0
+    } else {
+        (x - 1).significant_bits()
+    }
+}
+
+// Generates the index tuples of a product of iterators, some of which may be finite. The indices
+// of iterators that are not known to be finite are interleaved in Z-order. Whenever the Z-order
+// counter reaches the end of a cube [0, 2^k)^n, the iterators that are now known to be finite
+// are removed from the Z-order and iterated lexicographically from then on, innermost, so that
+// only the remaining iterators' indices are interleaved. If all the iterators are finite, the
+// indices end once every combination has been produced.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct MixedTupleIndices {
+    pub indices: Vec<usize>,
+    pub done: bool,
+    z_coords: Vec<usize>,
+    z: ZOrderTupleIndices,
+    z_counter: u64,
+    lex_coords: Vec<usize>,
+    lex_lengths: Vec<usize>,
+    lex: Vec<usize>,
+}
+
+impl MixedTupleIndices {
+    pub(crate) fn new(size: usize) -> MixedTupleIndices {
+        MixedTupleIndices {
+            indices: vec![0; size],
+            done: false,
+            z_coords: (0..size).collect(),
+            z: ZOrderTupleIndices::new(u64::exact_from(size)),
+            z_counter: 0,
+            lex_coords: Vec::new(),
+            lex_lengths: Vec::new(),
+            lex: Vec::new(),
+        }
+    }
+
+    // lengths[i] is the length of the ith iterator, if it is known.
+    pub(crate) fn increment(&mut self, lengths: &[Option<usize>]) {
+        for j in (0..self.lex.len()).rev() {
+            self.lex[j] += 1;
+            if self.lex[j] < self.lex_lengths[j] {
+                self.indices[self.lex_coords[j]] = self.lex[j];
+                return;
+            }
+            self.lex[j] = 0;
+            self.indices[self.lex_coords[j]] = 0;
+        }
+        if self.z_coords.is_empty() {
+            self.done = true;
+            return;
+        }
+        self.z.increment();
+        self.z_counter += 1;
+        if self.z_counter.is_power_of_two() {
+            let log = u64::from(self.z_counter.trailing_zeros());
+            let n = u64::exact_from(self.z_coords.len());
+            if log % n == 0 {
+                self.end_of_cube(lengths, log / n);
+                if self.done {
+                    return;
+                }
+            }
+        }
+        for (&c, &i) in self.z_coords.iter().zip(self.z.0.iter()) {
+            self.indices[c] = usize::exact_from(i);
+        }
+    }
+
+    // Every tuple whose Z-ordered indices are all less than 2^bits has been generated, so the
+    // iterators whose lengths are at most 2^bits are finished with. An iterator whose length is
+    // known to be greater, because its values are shared with other tuples, stays in the Z-order
+    // until a later cube, so that the order doesn't depend on when its length became known.
+    fn end_of_cube(&mut self, lengths: &[Option<usize>], bits: u64) {
+        let mut z_coords = Vec::new();
+        for &c in &self.z_coords {
+            match lengths[c] {
+                Some(0) => {
+                    self.done = true;
+                    return;
+                }
+                Some(length) if u64::exact_from(length) <= u64::power_of_2(bits) => {
+                    self.lex_coords.push(c);
+                    self.lex_lengths.push(length);
+                    self.lex.push(0);
+                    self.indices[c] = 0;
+                }
+                _ => z_coords.push(c),
+            }
+        }
+        if z_coords.len() == self.z_coords.len() {
+            return;
+        }
+        if z_coords.is_empty() {
+            self.done = true;
+            return;
+        }
+        let n = u64::exact_from(z_coords.len());
+        self.z_coords = z_coords;
+        // Skip the cube [0, 2^bits)^n, which has already been generated.
+        self.z = ZOrderTupleIndices::new(n);
+        *self.z.0.last_mut().unwrap() = u64::power_of_2(bits);
+        self.z_counter = u64::power_of_2(bits * n);
+    }
+
+    // Positions the indices at the start of the cube [0, 2^k)^size that contains the Z-order index
+    // z, assuming that no iterator has been found to be finite.
+    pub(crate) fn from_z_index(size: usize, z: u64) -> MixedTupleIndices {
+        let mut indices = MixedTupleIndices::new(size);
+        if size != 0 {
+            indices.z = ZOrderTupleIndices::from_index(u64::exact_from(size), z);
+            indices.z_counter = z;
+            for (i, &x) in indices.indices.iter_mut().zip(indices.z.0.iter()) {
+                *i = usize::exact_from(x);
+            }
+        }
+        indices
+    }
+
+    // The number of tuples generated before the current one, or None if it doesn't fit in a u64
+    // or the indices are done. length(c, bits) must return the length of the cth iterator if it is
+    // at most 2^bits.
+    pub(crate) fn index<F: FnMut(usize, u64) -> Option<usize>>(
+        &self,
+        mut length: F,
+    ) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+        // The current Z-ordered indices are in the cube [0, 2^bits)^n, and so are all the earlier
+        // ones.
+        let bits = self
+            .z
+            .0
+            .iter()
+            .map(|i| i.significant_bits())
+            .max()
+            .unwrap_or(0);
+        let bounds: Vec<Option<u64>> = self
+            .z_coords
+            .iter()
+            .map(|&c| length(c, bits).map(u64::exact_from))
+            .collect();
+        let (lex_count, lex_index) = lex_count_and_index(&self.lex_lengths, &self.lex)?;
+        let index = lex_count.checked_mul(z_points_below(&bounds, self.z_counter))?;
+        if z_point_is_valid(&bounds, &self.z.0) {
+            index.checked_add(lex_index)
+        } else {
+            Some(index)
+        }
+    }
+
+    // The number of tuples generated before the one with these indices, or None if it doesn't fit
+    // in a u64. Each index must be less than the length of its iterator. length is as in index.
+    pub(crate) fn index_of_indices<F: FnMut(usize, u64) -> Option<usize>>(
+        indices: &[usize],
+        mut length: F,
+    ) -> Option<u64> {
+        let mut z_coords: Vec<usize> = (0..indices.len()).collect();
+        let mut lex_coords = Vec::new();
+        let mut lex_lengths = Vec::new();
+        // Find the iterators that were finished with before the tuple was reached, in the order
+        // in which they were.
+        let mut bits;
+        loop {
+            bits = z_coords
+                .iter()
+                .map(|&c| indices[c].significant_bits())
+                .max()
+                .unwrap_or(0);
+            if bits == 0 {
+                break;
+            }
+            let mut finished = Vec::new();
+            for &c in &z_coords {
+                if let Some(len) = length(c, bits - 1) {
+                    finished.push((c, len));
+                }
+            }
+            let level = match finished.iter().map(|&(_, len)| ceiling_log_2(len)).min() {
+                Some(level) => level,
+                None => break,
+            };
+            for (c, len) in finished {
+                if ceiling_log_2(len) == level {
+                    z_coords.retain(|&d| d != c);
+                    lex_coords.push(c);
+                    lex_lengths.push(len);
+                }
+            }
+        }
+        let bounds: Vec<Option<u64>> = z_coords
+            .iter()
+            .map(|&c| length(c, bits).map(u64::exact_from))
+            .collect();
+        let z = ZOrderTupleIndices(
+            z_coords
+                .iter()
+                .map(|&c| u64::exact_from(indices[c]))
+                .collect(),
+        )
+        .to_index()?;
+        let lex: Vec<usize> = lex_coords.iter().map(|&c| indices[c]).collect();
+        let (lex_count, lex_index) = lex_count_and_index(&lex_lengths, &lex)?;
+        lex_count
+            .checked_mul(z_points_below(&bounds, z))?
+            .checked_add(lex_index)
+    }
+
+    // The indices of the nth tuple, which are done if there are n tuples or fewer, or None if the
+    // indices can't be represented. length is as in index.
+    pub(crate) fn from_index<F: FnMut(usize, u64) -> Option<usize>>(
+        size: usize,
+        n: u64,
+        mut length: F,
+    ) -> Option<MixedTupleIndices> {
+        let mut done = MixedTupleIndices::new(size);
+        done.done = true;
+        let mut z_coords: Vec<usize> = (0..size).collect();
+        let mut lex_coords = Vec::new();
+        let mut lex_lengths = Vec::new();
+        let mut lex_count = 1u64;
+        let mut bits = 0;
+        // Find the cube [0, 2^bits)^size that contains the nth tuple. The iterators whose lengths
+        // are at most 2^bits are finished with at the end of it.
+        let bounds = loop {
+            let mut bounds = Vec::new();
+            for &c in &z_coords {
+                let len = length(c, bits);
+                if len == Some(0) {
+                    return Some(done);
+                }
+                bounds.push(len.map(u64::exact_from));
+            }
+            let cube_count = bounds.iter().try_fold(lex_count, |count, &bound| {
+                let side = match bound {
+                    Some(bound) => bound,
+                    None if bits < u64::WIDTH => u64::power_of_2(bits),
+                    None => return None,
+                };
+                count.checked_mul(side)
+            });
+            match cube_count {
+                Some(count) if n >= count => {}
+                _ => break bounds,
+            }
+            let mut remaining = Vec::new();
+            for (c, bound) in z_coords.into_iter().zip(bounds) {
+                match bound {
+                    Some(len) => {
+                        lex_coords.push(c);
+                        lex_lengths.push(usize::exact_from(len));
+                        lex_count *= len;
+                    }
+                    None => remaining.push(c),
+                }
+            }
+            z_coords = remaining;
+            if z_coords.is_empty() {
+                return Some(done);
+            }
+            bits += 1;
+        };
+        let z_bits = bits.checked_mul(u64::exact_from(z_coords.len()))?;
+        if z_bits > u64::WIDTH {
+            return None;
+        }
+        let z = z_point(&bounds, n / lex_count, z_bits)?;
+        let mut indices = MixedTupleIndices::from_z_index(z_coords.len(), z);
+        let mut tuple = vec![0; size];
+        for (&c, &i) in z_coords.iter().zip(indices.indices.iter()) {
+            tuple[c] = i;
+        }
+        let mut lex_index = n % lex_count;
+        let mut lex = vec![0; lex_lengths.len()];
+        for j in (0..lex.len()).rev() {
+            let len = u64::exact_from(lex_lengths[j]);
+            lex[j] = usize::exact_from(lex_index % len);
+            lex_index /= len;
+            tuple[lex_coords[j]] = lex[j];
+        }
+        indices.indices = tuple;
+        indices.z_coords = z_coords;
+        indices.lex_coords = lex_coords;
+        indices.lex_lengths = lex_lengths;
+        indices.lex = lex;
+        Some(indices)
+    }
+}
+
+// The number of combinations of the lexicographically iterated indices, and the position of lex
+// among them.
+fn lex_count_and_index(lengths: &[usize], lex: &[usize]) -> Option<(u64, u64)> {
+    let mut count = 1u64;
+    let mut index = 0u64;
+    for (&len, &i) in lengths.iter().zip(lex.iter()) {
+        let len = u64::exact_from(len);
+        count = count.checked_mul(len)?;
+        index = index.checked_mul(len)?.checked_add(u64::exact_from(i))?;
+    }
+    Some((count, index))
+}
+
+// The lengths of the coordinate lists vary, so the number of Z-ordered coordinates is saved
+// before them.
+impl Resumable for MixedTupleIndices {
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.done.save_state(state);
+        let size = self.indices.len();
+        state.extend(self.indices.iter().map(|&i| u64::exact_from(i)));
+        state.push(u64::exact_from(self.z_coords.len()));
+        state.extend(self.z_coords.iter().map(|&c| u64::exact_from(c)));
+        self.z.save_state(state);
+        state.push(self.z_counter);
+        for j in 0..size - self.z_coords.len() {
+            state.push(u64::exact_from(self.lex_coords[j]));
+            state.push(u64::exact_from(self.lex_lengths[j]));
+            state.push(u64::exact_from(self.lex[j]));
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.done.restore_state(state)?;
+        let size = self.indices.len();
+        for i in &mut self.indices {
+            *i = state.read_usize(usize::MAX)?;
+        }
+        let z_len = state.read_usize(size + 1)?;
+        self.z_coords.clear();
+        for _ in 0..z_len {
+            self.z_coords.push(state.read_usize(size)?);
+        }
+        self.z = ZOrderTupleIndices::new(u64::exact_from(z_len));
+        self.z.restore_state(state)?;
+        self.z_counter = state.read()?;
+        self.lex_coords.clear();
+        self.lex_lengths.clear();
+        self.lex.clear();
+        for _ in z_len..size {
+            self.lex_coords.push(state.read_usize(size)?);
+            let length = state.read_usize(usize::MAX)?;
+            self.lex_lengths.push(length);
+            self.lex.push(state.read_usize(length)?);
+        }
+        Ok(())
+    }
+}
+
 macro_rules! random_tuple_from_single {
     (
         $repeated_tuple: ty, $struct_name: ident, $fn_name: ident,
@@ -73,6 +572,8 @@ macro_rules! random_tuple_from_single {
             }
         }
 
+        resumable!([I: Iterator], $struct_name<I>, [0], [I: Resumable,]);
+
         //TODO test
         pub fn $fn_name<I: Iterator>(xs: I) -> $struct_name<I> {
             $struct_name(xs)
@@ -192,14 +693,21 @@ macro_rules! random_tuple {
             }
         }
 
+        resumable!(
+            [$($it_type: Iterator),*],
+            $struct_name<$($it_type),*>,
+            [$($it),*],
+            [$($it_type: Resumable,)*]
+        );
+
         //TODO test
         #[allow(unknown_lints, too_many_arguments)]
-        pub fn $fn_name<$($it_type: Iterator),*>(seed: &[u32],
-                                                      $($it_gen: &dyn Fn(&[u32]) -> $it_type),*)
-                                                      -> $struct_name<$($it_type),*> {
+        pub fn $fn_name<$($it_type: Generator + ?Sized),*>(seed: &Seed,
+                                                              $($it_gen: &$it_type),*)
+                                                              -> $struct_name<$($it_type::Iter),*> {
             $struct_name {
                 $(
-                    $it: $it_gen(&scramble(seed, $it_name))
+                    $it: $it_gen.generate(&seed.derive($it_name))
                 ),*
             }
         }
@@ -269,3 +777,556 @@ random_tuple!(
     [O, ts_gen, ts, x_6, "ts"],
     [P, ss_gen, ss, x_7, "ss"]
 );
+
+macro_rules! exhaustive_tuple {
+    (
+        $struct_name: ident, $fn_name: ident, $size: expr,
+        $(
+            [ $it_type: ident, $it: ident, $i: expr, $elem: ident ]
+        ),*
+    ) => {
+        pub struct $struct_name<$($it_type: Iterator),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            $(
+                $it: CachedIterator<$it_type>,
+            )*
+            i: ZOrderTupleIndices,
+        }
+
+        impl<$($it_type: Iterator),*> Iterator for $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            type Item = ($($it_type::Item),*);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                $(
+                    let $elem = self.$it.get(usize::exact_from(self.i.0[$i])).unwrap();
+                )*
+                self.i.increment();
+                Some(($($elem),*))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match self.i.to_index().and_then(|i| i.checked_add(u64::exact_from(n))) {
+                    Some(i) => self.i = ZOrderTupleIndices::from_index($size, i),
+                    None => {
+                        for _ in 0..n {
+                            self.i.increment();
+                        }
+                    }
+                }
+                self.next()
+            }
+        }
+
+        impl<$($it_type: Iterator),*> $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            // Returns the index of t, or None if one of its values hasn't been generated by the
+            // corresponding input yet, or if the index doesn't fit in a u64. The inputs are
+            // assumed to generate distinct values.
+            pub fn index_of(&self, t: &($($it_type::Item),*)) -> Option<u64>
+            where
+                $($it_type::Item: PartialEq),*
+            {
+                let ($(ref $elem),*) = *t;
+                ZOrderTupleIndices(vec![$(u64::exact_from(self.$it.position($elem)?)),*])
+                    .to_index()
+            }
+        }
+
+        // Only the indices are saved; the cached values are regenerated from the inputs as needed.
+        resumable!(
+            [$($it_type: Iterator),*],
+            $struct_name<$($it_type),*>,
+            [i],
+            [$($it_type::Item: Clone,)*]
+        );
+
+        // All input iterators must be infinite.
+        #[allow(unknown_lints, too_many_arguments)]
+        pub fn $fn_name<$($it_type: Iterator),*>($($it: $it_type),*) -> $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            $struct_name {
+                $(
+                    $it: CachedIterator::new($it),
+                )*
+                i: ZOrderTupleIndices::new($size),
+            }
+        }
+    }
+}
+
+exhaustive_tuple!(
+    ExhaustivePairs,
+    exhaustive_pairs,
+    2,
+    [I, xs, 0, x],
+    [J, ys, 1, y]
+);
+exhaustive_tuple!(
+    ExhaustiveTriples,
+    exhaustive_triples,
+    3,
+    [I, xs, 0, x],
+    [J, ys, 1, y],
+    [K, zs, 2, z]
+);
+exhaustive_tuple!(
+    ExhaustiveQuadruples,
+    exhaustive_quadruples,
+    4,
+    [I, xs, 0, x],
+    [J, ys, 1, y],
+    [K, zs, 2, z],
+    [L, ws, 3, w]
+);
+exhaustive_tuple!(
+    ExhaustiveQuintuples,
+    exhaustive_quintuples,
+    5,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4]
+);
+exhaustive_tuple!(
+    ExhaustiveSextuples,
+    exhaustive_sextuples,
+    6,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5]
+);
+exhaustive_tuple!(
+    ExhaustiveSeptuples,
+    exhaustive_septuples,
+    7,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5],
+    [O, ts, 6, x_6]
+);
+exhaustive_tuple!(
+    ExhaustiveOctuples,
+    exhaustive_octuples,
+    8,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5],
+    [O, ts, 6, x_6],
+    [P, ss, 7, x_7]
+);
+
+macro_rules! exhaustive_pairs_log {
+    (
+        $struct_name: ident,
+        $fn_name: ident,
+        $x_index_fn: expr,
+        $y_index_fn: expr,
+        $from_indices_fn: expr
+    ) => {
+        pub struct $struct_name<I: Iterator, J: Iterator>
+        where
+            I::Item: Clone,
+            J::Item: Clone,
+        {
+            xs: CachedIterator<I>,
+            ys: CachedIterator<J>,
+            i: LogPairIndices,
+        }
+
+        impl<I: Iterator, J: Iterator> Iterator for $struct_name<I, J>
+        where
+            I::Item: Clone,
+            J::Item: Clone,
+        {
+            type Item = (I::Item, J::Item);
+
+            fn next(&mut self) -> Option<(I::Item, J::Item)> {
+                let x = self.xs.get($x_index_fn(&self.i)).unwrap();
+                let y = self.ys.get($y_index_fn(&self.i)).unwrap();
+                self.i.increment();
+                Some((x, y))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<(I::Item, J::Item)> {
+                match self.i.to_index().checked_add(u64::exact_from(n)) {
+                    Some(i) if i != u64::MAX => self.i = LogPairIndices::from_index(i),
+                    _ => {
+                        for _ in 0..n {
+                            self.i.increment();
+                        }
+                    }
+                }
+                self.next()
+            }
+        }
+
+        impl<I: Iterator, J: Iterator> $struct_name<I, J>
+        where
+            I::Item: Clone,
+            J::Item: Clone,
+        {
+            // Returns the index of p, or None if one of its values hasn't been generated by the
+            // corresponding input yet, or if the index doesn't fit in a u64. The inputs are
+            // assumed to generate distinct values.
+            pub fn index_of(&self, p: &(I::Item, J::Item)) -> Option<u64>
+            where
+                I::Item: PartialEq,
+                J::Item: PartialEq,
+            {
+                let x = self.xs.position(&p.0)?;
+                let y = self.ys.position(&p.1)?;
+                $from_indices_fn(x, y).map(|i: LogPairIndices| i.to_index())
+            }
+        }
+
+        resumable!(
+            [I: Iterator, J: Iterator],
+            $struct_name<I, J>,
+            [i],
+            [I::Item: Clone, J::Item: Clone,]
+        );
+
+        // Both input iterators must be infinite.
+        pub fn $fn_name<I: Iterator, J: Iterator>(xs: I, ys: J) -> $struct_name<I, J>
+        where
+            I::Item: Clone,
+            J::Item: Clone,
+        {
+            $struct_name {
+                xs: CachedIterator::new(xs),
+                ys: CachedIterator::new(ys),
+                i: LogPairIndices::new(),
+            }
+        }
+    };
+}
+
+exhaustive_pairs_log!(
+    ExhaustivePairsLog,
+    exhaustive_pairs_log,
+    |i: &LogPairIndices| i.indices().0,
+    |i: &LogPairIndices| i.indices().1,
+    LogPairIndices::from_indices
+);
+exhaustive_pairs_log!(
+    ExhaustivePairsLogSwapped,
+    exhaustive_pairs_log_swapped,
+    |i: &LogPairIndices| i.indices().1,
+    |i: &LogPairIndices| i.indices().0,
+    |x, y| LogPairIndices::from_indices(y, x)
+);
+
+macro_rules! exhaustive_tuple_mixed {
+    (
+        $struct_name: ident, $fn_name: ident, $size: expr,
+        $(
+            [ $it_type: ident, $it: ident, $i: expr, $elem: ident ]
+        ),*
+    ) => {
+        pub struct $struct_name<$($it_type: Iterator),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            $(
+                $it: CachedIterator<$it_type>,
+            )*
+            i: MixedTupleIndices,
+        }
+
+        impl<$($it_type: Iterator),*> Iterator for $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            type Item = ($($it_type::Item),*);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if self.i.done {
+                        return None;
+                    }
+                    $(
+                        let $elem = self.$it.get(self.i.indices[$i]);
+                    )*
+                    self.i.increment(&[$(self.$it.known_len()),*]);
+                    if let ($(Some($elem)),*) = ($($elem),*) {
+                        return Some(($($elem),*));
+                    }
+                }
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                let jumped = {
+                    let $struct_name { $(ref mut $it,)* ref mut i } = *self;
+                    let mut length = |c: usize, bits: u64| {
+                        $(
+                            if c == $i {
+                                return $it.len_up_to_power_of_2(bits);
+                            }
+                        )*
+                        unreachable!()
+                    };
+                    let target = i
+                        .index(&mut length)
+                        .and_then(|j| j.checked_add(u64::exact_from(n)));
+                    match target.and_then(|j| MixedTupleIndices::from_index($size, j, length)) {
+                        Some(indices) => {
+                            *i = indices;
+                            true
+                        }
+                        None => false,
+                    }
+                };
+                if !jumped {
+                    for _ in 0..n {
+                        self.next()?;
+                    }
+                }
+                self.next()
+            }
+        }
+
+        impl<$($it_type: Iterator),*> $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            // Returns the index of t, or None if one of its values hasn't been generated by the
+            // corresponding input yet, or if the index doesn't fit in a u64. The inputs are
+            // assumed to generate distinct values.
+            pub fn index_of(&mut self, t: &($($it_type::Item),*)) -> Option<u64>
+            where
+                $($it_type::Item: PartialEq),*
+            {
+                let ($(ref $elem),*) = *t;
+                let indices = [$(self.$it.position($elem)?),*];
+                let $struct_name { $(ref mut $it,)* .. } = *self;
+                MixedTupleIndices::index_of_indices(&indices, |c, bits| {
+                    $(
+                        if c == $i {
+                            return $it.len_up_to_power_of_2(bits);
+                        }
+                    )*
+                    unreachable!()
+                })
+            }
+        }
+
+        resumable!(
+            [$($it_type: Iterator),*],
+            $struct_name<$($it_type),*>,
+            [$($it,)* i],
+            [$($it_type::Item: Clone,)*]
+        );
+
+        // The input iterators may be finite or infinite.
+        #[allow(unknown_lints, too_many_arguments)]
+        pub fn $fn_name<$($it_type: Iterator),*>($($it: $it_type),*) -> $struct_name<$($it_type),*>
+        where
+            $($it_type::Item: Clone),*
+        {
+            $struct_name {
+                $(
+                    $it: CachedIterator::new($it),
+                )*
+                i: MixedTupleIndices::new($size),
+            }
+        }
+    }
+}
+
+exhaustive_tuple_mixed!(
+    ExhaustivePairsMixed,
+    exhaustive_pairs_mixed,
+    2,
+    [I, xs, 0, x],
+    [J, ys, 1, y]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveTriplesMixed,
+    exhaustive_triples_mixed,
+    3,
+    [I, xs, 0, x],
+    [J, ys, 1, y],
+    [K, zs, 2, z]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveQuadruplesMixed,
+    exhaustive_quadruples_mixed,
+    4,
+    [I, xs, 0, x],
+    [J, ys, 1, y],
+    [K, zs, 2, z],
+    [L, ws, 3, w]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveQuintuplesMixed,
+    exhaustive_quintuples_mixed,
+    5,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveSextuplesMixed,
+    exhaustive_sextuples_mixed,
+    6,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveSeptuplesMixed,
+    exhaustive_septuples_mixed,
+    7,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5],
+    [O, ts, 6, x_6]
+);
+exhaustive_tuple_mixed!(
+    ExhaustiveOctuplesMixed,
+    exhaustive_octuples_mixed,
+    8,
+    [I, xs, 0, x_0],
+    [J, ys, 1, x_1],
+    [K, zs, 2, x_2],
+    [L, ws, 3, x_3],
+    [M, vs, 4, x_4],
+    [N, us, 5, x_5],
+    [O, ts, 6, x_6],
+    [P, ss, 7, x_7]
+);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::primitive_ints::{exhaustive_unsigned, range_increasing};
+    use std::fmt::Debug;
+
+    // Checks that nth agrees with stepping through the first limit values, both from the start
+    // and after some values have been generated.
+    pub(crate) fn assert_nth<I: Iterator, F: Fn() -> I>(new: F, limit: usize)
+    where
+        I::Item: Debug + PartialEq,
+    {
+        let xs: Vec<I::Item> = new().take(limit + 1).collect();
+        for start in &[0, limit / 3] {
+            for n in 0..limit - start {
+                let mut it = new();
+                if *start != 0 {
+                    assert_eq!(it.nth(start - 1).as_ref(), xs.get(start - 1));
+                }
+                assert_eq!(it.nth(n).as_ref(), xs.get(start + n));
+                assert_eq!(it.next().as_ref(), xs.get(start + n + 1));
+            }
+        }
+    }
+
+    fn mixed_input(length: Option<u32>) -> Box<dyn Iterator<Item = u32>> {
+        match length {
+            Some(length) => Box::new(0..length),
+            None => Box::new(exhaustive_unsigned::<u32>()),
+        }
+    }
+
+    #[test]
+    fn test_mixed_tuple_nth_and_index_of() {
+        let lengths = [Some(0), Some(1), Some(2), Some(3), Some(5), Some(17), None];
+        for &a in &lengths {
+            for &b in &lengths {
+                assert_nth(
+                    || exhaustive_pairs_mixed(mixed_input(a), mixed_input(b)),
+                    300,
+                );
+                let mut pairs = exhaustive_pairs_mixed(mixed_input(a), mixed_input(b));
+                let xs: Vec<(u32, u32)> = (&mut pairs).take(300).collect();
+                for (i, p) in xs.iter().enumerate() {
+                    assert_eq!(pairs.index_of(p), Some(u64::exact_from(i)));
+                }
+                for &c in &[Some(3), None] {
+                    assert_nth(
+                        || exhaustive_triples_mixed(mixed_input(a), mixed_input(c), mixed_input(b)),
+                        150,
+                    );
+                    let mut triples =
+                        exhaustive_triples_mixed(mixed_input(a), mixed_input(c), mixed_input(b));
+                    let xs: Vec<(u32, u32, u32)> = (&mut triples).take(150).collect();
+                    for (i, t) in xs.iter().enumerate() {
+                        assert_eq!(triples.index_of(t), Some(u64::exact_from(i)));
+                    }
+                }
+            }
+        }
+        let new = || {
+            exhaustive_triples_mixed(
+                mixed_input(Some(3)),
+                mixed_input(None),
+                mixed_input(Some(200)),
+            )
+        };
+        let mut triples = new();
+        for _ in 0..100_000 {
+            triples.next();
+        }
+        let t = triples.next();
+        assert_eq!(new().nth(100_000), t);
+        assert_eq!(triples.index_of(&t.unwrap()), Some(100_000));
+
+        // A value that hasn't been generated yet.
+        let mut pairs =
+            exhaustive_pairs_mixed(range_increasing(0u8, 4), exhaustive_unsigned::<u16>());
+        assert_eq!(pairs.index_of(&(0, 0)), None);
+        assert_eq!(pairs.next(), Some((0, 0)));
+        assert_eq!(pairs.index_of(&(0, 0)), Some(0));
+    }
+
+    #[test]
+    fn test_mixed_tuple_checkpoints() {
+        assert_resumes(
+            || exhaustive_pairs_mixed(range_increasing(0u8, 4), exhaustive_unsigned::<u16>()),
+            100,
+        );
+        assert_resumes(
+            || {
+                exhaustive_triples_mixed(
+                    exhaustive_unsigned::<u16>(),
+                    range_increasing(0u8, 2),
+                    range_increasing(0u8, 9),
+                )
+            },
+            150,
+        );
+        // Every value is generated before the checkpoint.
+        assert_resumes(
+            || exhaustive_pairs_mixed(range_increasing(0u8, 2), range_increasing(0u8, 3)),
+            12,
+        );
+    }
+}
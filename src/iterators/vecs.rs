@@ -1,18 +1,24 @@
 use std::marker::PhantomData;
 
+use malachite_base::num::arithmetic::traits::PowerOf2;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::traits::ExactFrom;
-use malachite_base::num::logic::traits::BitConvertible;
+use malachite_base::num::logic::traits::{BitConvertible, SignificantBits};
 use malachite_nz::platform::Limb;
-use rand::{IsaacRng, Rng, SeedableRng};
+use rand::Rng;
 
-use iterators::common::scramble;
+use iterators::checkpoints::{CheckpointError, Resumable, StateReader};
+use iterators::common::{Seed, StreamVersion};
+use iterators::general::CachedIterator;
+use iterators::generators::Generator;
 use iterators::integers_geometric::{
     range_up_geometric_u32, u32s_geometric, RangeUpGeometricU32, U32sGeometric,
 };
 use iterators::naturals::{
     limbs_special_random_up_to_bits_old, special_random_natural_with_bits_old,
 };
+use iterators::rngs::{new_rng, SeededRng};
+use iterators::tuples::{z_points_below, LogPairIndices, MixedTupleIndices, ZOrderTupleIndices};
 
 pub struct RandomVecs<I>
 where
@@ -37,14 +43,16 @@ where
     }
 }
 
+resumable!([I: Iterator], RandomVecs<I>, [lengths, xs], [I: Resumable,]);
+
 //TODO test
-pub fn random_vecs<I>(seed: &[u32], scale: u32, xs_gen: &dyn Fn(&[u32]) -> I) -> RandomVecs<I>
+pub fn random_vecs<G>(seed: &Seed, scale: u32, xs_gen: &G) -> RandomVecs<G::Iter>
 where
-    I: Iterator,
+    G: Generator + ?Sized,
 {
     RandomVecs {
-        lengths: u32s_geometric(&scramble(seed, "lengths"), scale),
-        xs: xs_gen(&scramble(seed, "xs")),
+        lengths: u32s_geometric(&seed.derive("lengths"), scale),
+        xs: xs_gen.generate(&seed.derive("xs")),
     }
 }
 
@@ -71,28 +79,85 @@ where
     }
 }
 
-pub fn random_vecs_min_length<I>(
-    seed: &[u32],
+resumable!([I: Iterator], RandomVecsMinLength<I>, [lengths, xs], [I: Resumable,]);
+
+pub fn random_vecs_min_length<G>(
+    seed: &Seed,
     scale: u32,
     min_length: u64,
-    xs_gen: &dyn Fn(&[u32]) -> I,
-) -> RandomVecsMinLength<I>
+    xs_gen: &G,
+) -> RandomVecsMinLength<G::Iter>
 where
-    I: Iterator,
+    G: Generator + ?Sized,
 {
     RandomVecsMinLength {
         lengths: range_up_geometric_u32(
-            &scramble(seed, "lengths"),
+            &seed.derive("lengths"),
             scale,
             u32::exact_from(min_length),
         ),
-        xs: xs_gen(&scramble(seed, "xs")),
+        xs: xs_gen.generate(&seed.derive("xs")),
+    }
+}
+
+pub struct RandomVecsFixedLength<I>
+where
+    I: Iterator,
+{
+    length: usize,
+    xs: I,
+}
+
+impl<I> Iterator for RandomVecsFixedLength<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        Some((&mut self.xs).take(self.length).collect())
+    }
+}
+
+resumable!([I: Iterator], RandomVecsFixedLength<I>, [xs], [I: Resumable,]);
+
+pub fn random_vecs_fixed_length<G>(
+    seed: &Seed,
+    length: u64,
+    xs_gen: &G,
+) -> RandomVecsFixedLength<G::Iter>
+where
+    G: Generator + ?Sized,
+{
+    RandomVecsFixedLength {
+        length: usize::exact_from(length),
+        xs: xs_gen.generate(&seed.derive("xs")),
+    }
+}
+
+// V1 generates the values from limbs, so its outputs depend on the limb width; later versions
+// generate them from u64s.
+fn special_random_unsigned_words<T: PrimitiveUnsigned>(
+    rng: &mut SeededRng,
+    version: StreamVersion,
+    bits: u64,
+) -> Vec<T> {
+    match version {
+        StreamVersion::V1 => {
+            let limbs: Vec<Limb> = limbs_special_random_up_to_bits_old(rng, bits);
+            T::vec_from_other_type_slice(&limbs)
+        }
+        StreamVersion::V2 => {
+            let words: Vec<u64> = limbs_special_random_up_to_bits_old(rng, bits);
+            T::vec_from_other_type_slice(&words)
+        }
     }
 }
 
 pub struct SpecialRandomUnsignedVecs<T: PrimitiveUnsigned> {
     lengths: U32sGeometric,
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
+    version: StreamVersion,
     boo: PhantomData<*const T>,
 }
 
@@ -104,27 +169,33 @@ impl<T: PrimitiveUnsigned> Iterator for SpecialRandomUnsignedVecs<T> {
         if len == 0 {
             return Some(Vec::new());
         }
-        let limbs: Vec<Limb> =
-            limbs_special_random_up_to_bits_old(&mut self.rng, u64::from(len << T::LOG_WIDTH));
-        Some(T::vec_from_other_type_slice(&limbs))
+        Some(special_random_unsigned_words(
+            &mut self.rng,
+            self.version,
+            u64::from(len << T::LOG_WIDTH),
+        ))
     }
 }
 
+resumable!([T: PrimitiveUnsigned], SpecialRandomUnsignedVecs<T>, [lengths, rng], []);
+
 //TODO test
 pub fn special_random_unsigned_vecs<T: PrimitiveUnsigned>(
-    seed: &[u32],
+    seed: &Seed,
     scale: u32,
 ) -> SpecialRandomUnsignedVecs<T> {
     SpecialRandomUnsignedVecs {
-        lengths: u32s_geometric(&scramble(seed, "lengths"), scale),
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "xs"))),
+        lengths: u32s_geometric(&seed.derive("lengths"), scale),
+        rng: new_rng(&seed.derive("xs")),
+        version: seed.version(),
         boo: PhantomData,
     }
 }
 
 pub struct SpecialRandomUnsignedVecsMinLength<T: PrimitiveUnsigned> {
     lengths: RangeUpGeometricU32,
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
+    version: StreamVersion,
     boo: PhantomData<*const T>,
 }
 
@@ -136,32 +207,73 @@ impl<T: PrimitiveUnsigned> Iterator for SpecialRandomUnsignedVecsMinLength<T> {
         if len == 0 {
             return Some(Vec::new());
         }
-        let limbs: Vec<Limb> =
-            limbs_special_random_up_to_bits_old(&mut self.rng, u64::from(len << T::LOG_WIDTH));
-        Some(T::vec_from_other_type_slice(&limbs))
+        Some(special_random_unsigned_words(
+            &mut self.rng,
+            self.version,
+            u64::from(len << T::LOG_WIDTH),
+        ))
     }
 }
 
+resumable!([T: PrimitiveUnsigned], SpecialRandomUnsignedVecsMinLength<T>, [lengths, rng], []);
+
 //TODO test
 pub fn special_random_unsigned_vecs_min_length<T: PrimitiveUnsigned>(
-    seed: &[u32],
+    seed: &Seed,
     scale: u32,
     min_length: u64,
 ) -> SpecialRandomUnsignedVecsMinLength<T> {
     SpecialRandomUnsignedVecsMinLength {
         lengths: range_up_geometric_u32(
-            &scramble(seed, "lengths"),
+            &seed.derive("lengths"),
             scale,
             u32::exact_from(min_length),
         ),
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "xs"))),
+        rng: new_rng(&seed.derive("xs")),
+        version: seed.version(),
+        boo: PhantomData,
+    }
+}
+
+pub struct SpecialRandomUnsignedVecsFixedLength<T: PrimitiveUnsigned> {
+    length: u64,
+    rng: SeededRng,
+    version: StreamVersion,
+    boo: PhantomData<*const T>,
+}
+
+impl<T: PrimitiveUnsigned> Iterator for SpecialRandomUnsignedVecsFixedLength<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.length == 0 {
+            return Some(Vec::new());
+        }
+        let mut xs =
+            special_random_unsigned_words(&mut self.rng, self.version, self.length << T::LOG_WIDTH);
+        // If T is narrower than a word, the last word may produce extra zeros.
+        xs.truncate(usize::exact_from(self.length));
+        Some(xs)
+    }
+}
+
+resumable!([T: PrimitiveUnsigned], SpecialRandomUnsignedVecsFixedLength<T>, [rng], []);
+
+pub fn special_random_unsigned_vecs_fixed_length<T: PrimitiveUnsigned>(
+    seed: &Seed,
+    length: u64,
+) -> SpecialRandomUnsignedVecsFixedLength<T> {
+    SpecialRandomUnsignedVecsFixedLength {
+        length,
+        rng: new_rng(&seed.derive("xs")),
+        version: seed.version(),
         boo: PhantomData,
     }
 }
 
 pub struct SpecialRandomBoolVecs {
     lengths: U32sGeometric,
-    rng: Box<IsaacRng>,
+    rng: SeededRng,
 }
 
 impl Iterator for SpecialRandomBoolVecs {
@@ -181,10 +293,730 @@ impl Iterator for SpecialRandomBoolVecs {
     }
 }
 
+resumable!([], SpecialRandomBoolVecs, [lengths, rng], []);
+
 //TODO test
-pub fn special_random_bool_vecs(seed: &[u32], scale: u32) -> SpecialRandomBoolVecs {
+pub fn special_random_bool_vecs(seed: &Seed, scale: u32) -> SpecialRandomBoolVecs {
     SpecialRandomBoolVecs {
-        lengths: u32s_geometric(&scramble(seed, "lengths"), scale),
-        rng: Box::new(IsaacRng::from_seed(&scramble(seed, "xs"))),
+        lengths: u32s_geometric(&seed.derive("lengths"), scale),
+        rng: new_rng(&seed.derive("xs")),
+    }
+}
+
+pub struct ShortlexVecs<I: Iterator>
+where
+    I::Item: Clone,
+{
+    xs: CachedIterator<I>,
+    min_length: usize,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<I: Iterator> ShortlexVecs<I>
+where
+    I::Item: Clone,
+{
+    // Returns the index of the Vec whose values are at these indices of xs, or None if it doesn't
+    // fit in a u64.
+    fn index_of_indices(&mut self, indices: &[usize]) -> Option<u64> {
+        if indices.len() < self.min_length {
+            return None;
+        }
+        let base = u64::exact_from(self.xs.total_len());
+        if base == 1 {
+            return Some(u64::exact_from(indices.len() - self.min_length));
+        }
+        let mut index = 0u64;
+        for length in self.min_length..indices.len() {
+            index = index.checked_add(base.checked_pow(u32::exact_from(length))?)?;
+        }
+        let mut offset = 0u64;
+        for &i in indices {
+            offset = offset.checked_mul(base)?.checked_add(u64::exact_from(i))?;
+        }
+        index.checked_add(offset)
+    }
+
+    // Moves to the Vec with index n, which is the next one generated.
+    fn jump_to(&mut self, mut n: u64) {
+        let base = u64::exact_from(self.xs.total_len());
+        match base {
+            0 => self.done = n != 0,
+            1 => self.indices = vec![0; self.min_length + usize::exact_from(n)],
+            _ => {
+                let mut length = self.min_length;
+                loop {
+                    match base.checked_pow(u32::exact_from(length)) {
+                        Some(count) if n >= count => n -= count,
+                        _ => break,
+                    }
+                    length += 1;
+                }
+                self.indices = vec![0; length];
+                for i in self.indices.iter_mut().rev() {
+                    *i = usize::exact_from(n % base);
+                    n /= base;
+                }
+            }
+        }
+    }
+
+    // Returns the index of xs, or None if it is never generated or its index doesn't fit in a
+    // u64. The input values are assumed to be distinct.
+    pub fn index_of(&mut self, xs: &[I::Item]) -> Option<u64>
+    where
+        I::Item: PartialEq,
+    {
+        self.xs.total_len();
+        let indices: Option<Vec<usize>> = xs.iter().map(|x| self.xs.position(x)).collect();
+        self.index_of_indices(&indices?)
+    }
+}
+
+impl<I: Iterator> Iterator for ShortlexVecs<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done {
+            return None;
+        }
+        let xs = &mut self.xs;
+        let v = match self.indices.iter().map(|&i| xs.get(i)).collect() {
+            Some(v) => v,
+            // xs is empty, and min_length is nonzero
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        if xs.get(0).is_none() {
+            self.done = true;
+            return Some(v);
+        }
+        let mut j = self.indices.len();
+        loop {
+            if j == 0 {
+                // Every Vec of the current length has been generated; all indices are now 0.
+                self.indices.push(0);
+                break;
+            }
+            j -= 1;
+            self.indices[j] += 1;
+            if xs.get(self.indices[j]).is_some() {
+                break;
+            }
+            self.indices[j] = 0;
+        }
+        Some(v)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<I::Item>> {
+        if !self.done {
+            let indices = self.indices.clone();
+            match self
+                .index_of_indices(&indices)
+                .and_then(|i| i.checked_add(u64::exact_from(n)))
+            {
+                Some(i) => self.jump_to(i),
+                None => {
+                    for _ in 0..n {
+                        self.next()?;
+                    }
+                }
+            }
+        }
+        self.next()
+    }
+}
+
+impl<I: Iterator> Resumable for ShortlexVecs<I>
+where
+    I::Item: Clone,
+{
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.xs.save_state(state);
+        self.done.save_state(state);
+        state.push(u64::exact_from(self.indices.len()));
+        state.extend(self.indices.iter().map(|&i| u64::exact_from(i)));
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.xs.restore_state(state)?;
+        self.done.restore_state(state)?;
+        let len = state.read_usize(usize::MAX)?;
+        if len < self.min_length {
+            return Err(CheckpointError::InvalidValue(u64::exact_from(len)));
+        }
+        self.indices.clear();
+        for _ in 0..len {
+            self.indices.push(state.read_usize(usize::MAX)?);
+        }
+        Ok(())
+    }
+}
+
+pub struct InterleavedVecs<I: Iterator>
+where
+    I::Item: Clone,
+{
+    xs: CachedIterator<I>,
+    min_length: usize,
+    i: LogPairIndices,
+    // The indices of the Vecs of length min_length + k, or None once they have all been
+    // generated.
+    lengths: Vec<Option<MixedTupleIndices>>,
+}
+
+impl<I: Iterator> Iterator for InterleavedVecs<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        loop {
+            if self.xs.known_len() == Some(0)
+                && (self.min_length != 0 || self.lengths.first().map(Option::is_none) == Some(true))
+            {
+                return None;
+            }
+            // Vecs of length min_length + k are chosen 1 / 2^(k + 1) of the time.
+            let k = self.i.indices().1;
+            self.i.increment();
+            while self.lengths.len() <= k {
+                let length = self.min_length + self.lengths.len();
+                self.lengths.push(Some(MixedTupleIndices::new(length)));
+            }
+            let xs = &mut self.xs;
+            let finished = if let Some(ref mut indices) = self.lengths[k] {
+                if indices.done {
+                    true
+                } else {
+                    let v: Option<Vec<I::Item>> =
+                        indices.indices.iter().map(|&j| xs.get(j)).collect();
+                    let lengths = vec![xs.known_len(); indices.indices.len()];
+                    indices.increment(&lengths);
+                    if v.is_some() {
+                        return v;
+                    }
+                    false
+                }
+            } else {
+                false
+            };
+            if finished {
+                self.lengths[k] = None;
+            }
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<I::Item>> {
+        let steps = self.i.to_index();
+        let target = self.outputs_before(steps).checked_add(u64::exact_from(n));
+        // Find the step that generates the target Vec: the last one with at most target Vecs
+        // before it.
+        let mut low = steps;
+        let mut high = None;
+        if let Some(target) = target {
+            let mut width = 1u64;
+            while let Some(s) = low.checked_add(width) {
+                if s == u64::MAX {
+                    break;
+                }
+                if self.outputs_before(s) > target {
+                    high = Some(s);
+                    break;
+                }
+                low = s;
+                width = width.saturating_mul(2);
+            }
+        }
+        match high {
+            Some(mut high) => {
+                while high - low > 1 {
+                    let mid = low + (high - low) / 2;
+                    if self.outputs_before(mid) > target.unwrap() {
+                        high = mid;
+                    } else {
+                        low = mid;
+                    }
+                }
+                self.jump_to(low);
+            }
+            None => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+            }
+        }
+        self.next()
+    }
+}
+
+impl<I: Iterator> InterleavedVecs<I>
+where
+    I::Item: Clone,
+{
+    // The number of Vecs generated in the first steps steps. Step s chooses the length
+    // min_length + k, where k is the number of trailing zeros of s + 1, and tries the next Vec of
+    // that length in Z-order.
+    fn outputs_before(&mut self, steps: u64) -> u64 {
+        let mut outputs = 0;
+        for k in 0..steps.significant_bits() {
+            let tries = (steps >> k) - (steps >> (k + 1));
+            let length = self.min_length + usize::exact_from(k);
+            let bounds = if length == 0 {
+                Vec::new()
+            } else {
+                let length_u64 = u64::exact_from(length);
+                let bits = tries.significant_bits().div_ceil(length_u64);
+                vec![self.xs.len_up_to_power_of_2(bits).map(u64::exact_from); length]
+            };
+            outputs += z_points_below(&bounds, tries);
+        }
+        outputs
+    }
+
+    // Moves to the state after steps steps.
+    fn jump_to(&mut self, steps: u64) {
+        self.i = LogPairIndices::from_index(steps);
+        self.lengths.clear();
+        for k in 0..steps.significant_bits() {
+            let tries = (steps >> k) - (steps >> (k + 1));
+            let length = self.min_length + usize::exact_from(k);
+            // The indices are done after trying every Vec in [0, 2^d)^length, where d is the
+            // smallest number such that xs has at most 2^d values.
+            let mut done = None;
+            if length == 0 {
+                done = Some(0);
+            } else if tries != 0 {
+                let max_bits = (tries.significant_bits() - 1) / u64::exact_from(length);
+                if let Some(len) = self.xs.len_up_to_power_of_2(max_bits) {
+                    let d = if len <= 1 {
+                        0
+                    } else {
+                        u64::exact_from(len - 1).significant_bits()
+                    };
+                    done = Some(d * u64::exact_from(length));
+                }
+            }
+            self.lengths.push(match done {
+                Some(d) if tries > u64::power_of_2(d) => None,
+                Some(d) if tries == u64::power_of_2(d) => {
+                    let mut indices = MixedTupleIndices::new(length);
+                    indices.done = true;
+                    Some(indices)
+                }
+                _ => Some(MixedTupleIndices::from_z_index(length, tries)),
+            });
+        }
+    }
+
+    // Returns the index of xs, or None if it is never generated, if one of its values hasn't been
+    // generated by the input yet, or if its index doesn't fit in a u64. The input values are
+    // assumed to be distinct.
+    pub fn index_of(&mut self, xs: &[I::Item]) -> Option<u64>
+    where
+        I::Item: PartialEq,
+    {
+        if xs.len() < self.min_length {
+            return None;
+        }
+        let indices: Option<Vec<u64>> = xs
+            .iter()
+            .map(|x| self.xs.position(x).map(u64::exact_from))
+            .collect();
+        let z = ZOrderTupleIndices(indices?).to_index()?;
+        let steps = LogPairIndices::from_indices(usize::exact_from(z), xs.len() - self.min_length)?;
+        Some(self.outputs_before(steps.to_index()))
+    }
+}
+
+// Each length is saved as 0 once all of its Vecs have been generated, or as 1 followed by its
+// indices.
+impl<I: Iterator> Resumable for InterleavedVecs<I>
+where
+    I::Item: Clone,
+{
+    fn save_state(&self, state: &mut Vec<u64>) {
+        self.xs.save_state(state);
+        self.i.save_state(state);
+        state.push(u64::exact_from(self.lengths.len()));
+        for indices in &self.lengths {
+            match *indices {
+                Some(ref indices) => {
+                    state.push(1);
+                    indices.save_state(state);
+                }
+                None => state.push(0),
+            }
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        self.xs.restore_state(state)?;
+        self.i.restore_state(state)?;
+        let len = state.read_usize(usize::MAX)?;
+        self.lengths.clear();
+        for k in 0..len {
+            let mut present = false;
+            present.restore_state(state)?;
+            self.lengths.push(if present {
+                let mut indices = MixedTupleIndices::new(self.min_length + k);
+                indices.restore_state(state)?;
+                Some(indices)
+            } else {
+                None
+            });
+        }
+        Ok(())
+    }
+}
+
+pub enum ExhaustiveVecs<I: Iterator>
+where
+    I::Item: Clone,
+{
+    Shortlex(ShortlexVecs<I>),
+    Interleaved(InterleavedVecs<I>),
+}
+
+impl<I: Iterator> Iterator for ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        match *self {
+            ExhaustiveVecs::Shortlex(ref mut xss) => xss.next(),
+            ExhaustiveVecs::Interleaved(ref mut xss) => xss.next(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<I::Item>> {
+        match *self {
+            ExhaustiveVecs::Shortlex(ref mut xss) => xss.nth(n),
+            ExhaustiveVecs::Interleaved(ref mut xss) => xss.nth(n),
+        }
+    }
+}
+
+impl<I: Iterator> ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    // Returns the index of xs, or None if it is never generated or its index doesn't fit in a
+    // u64. For interleaved Vecs, None is also returned if one of the values of xs hasn't been
+    // generated by the input yet.
+    pub fn index_of(&mut self, xs: &[I::Item]) -> Option<u64>
+    where
+        I::Item: PartialEq,
+    {
+        match *self {
+            ExhaustiveVecs::Shortlex(ref mut xss) => xss.index_of(xs),
+            ExhaustiveVecs::Interleaved(ref mut xss) => xss.index_of(xs),
+        }
+    }
+}
+
+impl<I: Iterator> Resumable for ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    fn save_state(&self, state: &mut Vec<u64>) {
+        match *self {
+            ExhaustiveVecs::Shortlex(ref xss) => xss.save_state(state),
+            ExhaustiveVecs::Interleaved(ref xss) => xss.save_state(state),
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) -> Result<(), CheckpointError> {
+        match *self {
+            ExhaustiveVecs::Shortlex(ref mut xss) => xss.restore_state(state),
+            ExhaustiveVecs::Interleaved(ref mut xss) => xss.restore_state(state),
+        }
+    }
+}
+
+// Inputs with more values than this are never generated in shortlex order, since it would take too
+// long to get past the shortest Vecs.
+const MAX_SHORTLEX_VALUES: usize = 1 << 8;
+
+// If xs reports in its size_hint that it generates at most 256 (MAX_SHORTLEX_VALUES) values, the
+// Vecs are generated in shortlex order. Otherwise, Vecs of different lengths are interleaved, with
+// shorter Vecs appearing more often; xs may then be finite or infinite. So a finite xs with more
+// than 256 values, or whose size_hint has no upper bound, gets the interleaved order; use
+// exhaustive_vecs_shortlex_min_length to get shortlex order for it.
+pub fn exhaustive_vecs_min_length<I: Iterator>(xs: I, min_length: u64) -> ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    if xs.size_hint().1.unwrap_or(usize::MAX) <= MAX_SHORTLEX_VALUES {
+        exhaustive_vecs_shortlex_min_length(xs, min_length)
+    } else {
+        ExhaustiveVecs::Interleaved(InterleavedVecs {
+            xs: CachedIterator::new(xs),
+            min_length: usize::exact_from(min_length),
+            i: LogPairIndices::new(),
+            lengths: Vec::new(),
+        })
+    }
+}
+
+// Chooses the order in the same way as exhaustive_vecs_min_length, so only inputs that report at
+// most 256 values get shortlex order.
+pub fn exhaustive_vecs<I: Iterator>(xs: I) -> ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    exhaustive_vecs_min_length(xs, 0)
+}
+
+// Generates the Vecs in shortlex order, whatever xs reports in its size_hint. xs must be finite,
+// since otherwise no Vec longer than min_length is ever reached.
+pub fn exhaustive_vecs_shortlex_min_length<I: Iterator>(xs: I, min_length: u64) -> ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    let min_length = usize::exact_from(min_length);
+    ExhaustiveVecs::Shortlex(ShortlexVecs {
+        xs: CachedIterator::new(xs),
+        min_length,
+        indices: vec![0; min_length],
+        done: false,
+    })
+}
+
+pub fn exhaustive_vecs_shortlex<I: Iterator>(xs: I) -> ExhaustiveVecs<I>
+where
+    I::Item: Clone,
+{
+    exhaustive_vecs_shortlex_min_length(xs, 0)
+}
+
+pub struct ExhaustiveVecsFixedLength<I: Iterator>
+where
+    I::Item: Clone,
+{
+    xs: CachedIterator<I>,
+    i: MixedTupleIndices,
+}
+
+impl<I: Iterator> Iterator for ExhaustiveVecsFixedLength<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        loop {
+            if self.i.done {
+                return None;
+            }
+            let xs = &mut self.xs;
+            let v: Option<Vec<I::Item>> = self.i.indices.iter().map(|&j| xs.get(j)).collect();
+            let lengths = vec![xs.known_len(); self.i.indices.len()];
+            self.i.increment(&lengths);
+            if v.is_some() {
+                return v;
+            }
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Vec<I::Item>> {
+        let size = self.i.indices.len();
+        let xs = &mut self.xs;
+        let indices = self
+            .i
+            .index(|_, bits| xs.len_up_to_power_of_2(bits))
+            .and_then(|i| i.checked_add(u64::exact_from(n)))
+            .and_then(|i| {
+                MixedTupleIndices::from_index(size, i, |_, bits| xs.len_up_to_power_of_2(bits))
+            });
+        match indices {
+            Some(indices) => self.i = indices,
+            None => {
+                for _ in 0..n {
+                    self.next()?;
+                }
+            }
+        }
+        self.next()
+    }
+}
+
+impl<I: Iterator> ExhaustiveVecsFixedLength<I>
+where
+    I::Item: Clone,
+{
+    // Returns the index of xs, or None if it has the wrong length, if one of its values hasn't
+    // been generated by the input yet, or if its index doesn't fit in a u64. The input values are
+    // assumed to be distinct.
+    pub fn index_of(&mut self, xs: &[I::Item]) -> Option<u64>
+    where
+        I::Item: PartialEq,
+    {
+        if xs.len() != self.i.indices.len() {
+            return None;
+        }
+        let indices: Option<Vec<usize>> = xs.iter().map(|x| self.xs.position(x)).collect();
+        let xs = &mut self.xs;
+        MixedTupleIndices::index_of_indices(&indices?, |_, bits| xs.len_up_to_power_of_2(bits))
+    }
+}
+
+resumable!(
+    [I: Iterator],
+    ExhaustiveVecsFixedLength<I>,
+    [xs, i],
+    [I::Item: Clone,]
+);
+
+// xs may be finite or infinite.
+pub fn exhaustive_vecs_fixed_length<I: Iterator>(xs: I, length: u64) -> ExhaustiveVecsFixedLength<I>
+where
+    I::Item: Clone,
+{
+    ExhaustiveVecsFixedLength {
+        xs: CachedIterator::new(xs),
+        i: MixedTupleIndices::new(usize::exact_from(length)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use iterators::checkpoints::tests::assert_resumes;
+    use iterators::general::random;
+    use iterators::generators::RandomGenerator;
+    use iterators::primitive_ints::{exhaustive_unsigned, range_increasing};
+    use iterators::tuples::tests::assert_nth;
+
+    #[test]
+    fn test_exhaustive_vecs_interleaved_finite() {
+        // More values than MAX_SHORTLEX_VALUES, so the Vecs are interleaved
+        let mut pairs = HashSet::new();
+        for v in exhaustive_vecs(range_increasing(0u16, 299)).take(500_000) {
+            if v.len() == 2 {
+                assert!(pairs.insert(v));
+            }
+        }
+        assert_eq!(pairs.len(), 90_000);
+    }
+
+    #[test]
+    fn test_exhaustive_vecs_shortlex() {
+        // More values than MAX_SHORTLEX_VALUES
+        let xss: Vec<Vec<u16>> = exhaustive_vecs_shortlex(range_increasing(0u16, 299))
+            .skip(299)
+            .take(4)
+            .collect();
+        assert_eq!(xss, [vec![298], vec![299], vec![0, 0], vec![0, 1]]);
+        // No upper bound in the size_hint
+        let mut i = 0;
+        let xs = std::iter::from_fn(move || {
+            i += 1;
+            if i <= 3 {
+                Some(i)
+            } else {
+                None
+            }
+        });
+        let xss: Vec<Vec<u32>> = exhaustive_vecs_shortlex_min_length(xs, 1).take(5).collect();
+        assert_eq!(xss, [vec![1], vec![2], vec![3], vec![1, 1], vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_exhaustive_vecs_checkpoints() {
+        // Shortlex
+        assert_resumes(|| exhaustive_vecs(range_increasing(0u8, 2)), 50);
+        // Interleaved, with finite and infinite inputs
+        assert_resumes(|| exhaustive_vecs(range_increasing(0u16, 999)), 200);
+        assert_resumes(
+            || exhaustive_vecs_min_length(exhaustive_unsigned::<u32>(), 2),
+            200,
+        );
+        assert_resumes(
+            || exhaustive_vecs_fixed_length(range_increasing(0u8, 5), 3),
+            40,
+        );
+    }
+
+    // Inputs that report more than MAX_SHORTLEX_VALUES values, but generate only a few.
+    type Vecs = ExhaustiveVecs<Box<dyn Iterator<Item = u32>>>;
+
+    fn sparse(modulus: u32) -> Box<dyn Iterator<Item = u32>> {
+        Box::new((0..1000).filter(move |x| x % modulus == 0))
+    }
+
+    #[test]
+    fn test_exhaustive_vecs_nth_and_index_of() {
+        // With few input values, long Vecs take exponentially many steps to reach, so only a few
+        // Vecs are checked.
+        let news: Vec<(Box<dyn Fn() -> Vecs>, usize)> = vec![
+            (Box::new(|| exhaustive_vecs(Box::new(0..300))), 100_000),
+            (
+                Box::new(|| exhaustive_vecs_min_length(Box::new(exhaustive_unsigned()), 2)),
+                100_000,
+            ),
+            (
+                Box::new(|| exhaustive_vecs(Box::new(exhaustive_unsigned()))),
+                100_000,
+            ),
+            (Box::new(|| exhaustive_vecs(sparse(250))), 300),
+            (Box::new(|| exhaustive_vecs_min_length(sparse(1000), 3)), 12),
+            (Box::new(|| exhaustive_vecs(sparse(2000))), 12),
+            (Box::new(|| exhaustive_vecs(Box::new(0..3))), 300),
+        ];
+        for &(ref new, limit) in &news {
+            assert_nth(new, limit.min(300));
+            let mut xss = new();
+            let vs: Vec<Vec<u32>> = (&mut xss).take(limit.min(1000)).collect();
+            for (i, v) in vs.iter().enumerate() {
+                assert_eq!(xss.index_of(v), Some(u64::exact_from(i)));
+            }
+            let mut xss = new();
+            for _ in 0..limit - 1 {
+                xss.next();
+            }
+            let v = xss.next();
+            assert_eq!(new().nth(limit - 1), v);
+            assert_eq!(xss.index_of(&v.unwrap()), Some(u64::exact_from(limit - 1)));
+        }
+        // A value that hasn't been generated yet.
+        let mut xss = exhaustive_vecs(exhaustive_unsigned::<u32>());
+        assert_eq!(xss.index_of(&[0]), None);
+        assert_eq!(xss.index_of(&[]), Some(0));
+
+        for &length in &[0, 1, 2, 3] {
+            for modulus in &[1, 250, 1000, 2000] {
+                let new = || exhaustive_vecs_fixed_length(sparse(*modulus), length);
+                assert_nth(new, 200);
+                let mut xss = new();
+                let vs: Vec<Vec<u32>> = (&mut xss).take(200).collect();
+                for (i, v) in vs.iter().enumerate() {
+                    assert_eq!(xss.index_of(v), Some(u64::exact_from(i)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_vecs_generator() {
+        let seed = Seed::example();
+        let from_generator: Vec<Vec<u8>> = random_vecs(&seed, 2, &RandomGenerator::<u8>::new())
+            .take(10)
+            .collect();
+        let from_closure: Vec<Vec<u8>> = random_vecs(&seed, 2, &|seed: &Seed| random::<u8>(seed))
+            .take(10)
+            .collect();
+        assert_eq!(from_generator, from_closure);
     }
 }
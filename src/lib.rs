@@ -12,14 +12,25 @@ extern crate sha3;
 
 pub mod iterators {
     pub mod adaptors;
+    pub mod arrays;
+    #[macro_use]
+    pub mod checkpoints;
     pub mod common;
     pub mod dependent_pairs;
+    pub mod floats;
     pub mod general;
+    pub mod generators;
+    pub mod golden;
     pub mod integers;
     pub mod integers_geometric;
     pub mod naturals;
+    pub mod permutations;
     pub mod primitive_ints;
+    pub mod properties;
+    pub mod rngs;
     pub mod rounding_modes;
+    pub mod sets;
+    pub mod shrinkers;
     pub mod tuples;
     pub mod vecs;
 }